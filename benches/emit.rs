@@ -0,0 +1,48 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{hint::black_box, pin::pin};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use futures_lite::future;
+use winit_runtime::{emit, event::EventSource};
+
+static SOURCE: winit_runtime::EventSource!(&u32) = EventSource::new();
+
+fn emit_benches(c: &mut Criterion) {
+    c.bench_function("emit/0 listeners", |b| {
+        b.iter(|| emit!(SOURCE, black_box(&1)))
+    });
+
+    for count in [1, 8] {
+        c.bench_function(&format!("emit/{count} listeners"), |b| {
+            future::block_on(async {
+                let mut listeners = (0..count)
+                    .map(|_| Box::pin(SOURCE.on(|value, _| _ = black_box(value))))
+                    .collect::<Vec<_>>();
+
+                // Register listeners
+                for listener in &mut listeners {
+                    let _ = future::poll_once(listener.as_mut()).await;
+                }
+
+                b.iter(|| emit!(SOURCE, black_box(&1)));
+            })
+        });
+    }
+
+    c.bench_function("listener/register and drop", |b| {
+        b.iter(|| {
+            future::block_on(async {
+                let mut listener = pin!(SOURCE.on(|value, _| _ = black_box(value)));
+                let _ = future::poll_once(listener.as_mut()).await;
+            })
+        })
+    });
+}
+
+criterion_group!(benches, emit_benches);
+criterion_main!(benches);
@@ -0,0 +1,50 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::sync::Arc;
+
+use futures_lite::future;
+use winit::{event::WindowEvent, window::Window};
+use winit_runtime::{app::App, canvas::Canvas, window};
+
+fn main() {
+    App::new()
+        .window(Window::default_attributes().with_title("Canvas"))
+        .run(|main_window| async move {
+            let main_window = Arc::new(main_window);
+            let id = main_window.id();
+            let canvas = Canvas::new(main_window).unwrap();
+
+            future::or(canvas.maintain(), async {
+                loop {
+                    // wait for redraw request
+                    window()
+                        .once(|(window_id, event), _| {
+                            if window_id == id && matches!(event, WindowEvent::RedrawRequested) {
+                                Some(())
+                            } else {
+                                None
+                            }
+                        })
+                        .await;
+
+                    // draw gradient
+                    canvas
+                        .present(|size, frame| {
+                            for (i, pixel) in frame.iter_mut().enumerate() {
+                                let x = (i as u32 % size.width) * 255 / size.width;
+                                let y = (i as u32 / size.width) * 255 / size.height;
+
+                                *pixel = x << 16 | y << 8 | 0x80;
+                            }
+                        })
+                        .unwrap();
+                }
+            })
+            .await
+        })
+        .unwrap();
+}
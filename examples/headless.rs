@@ -0,0 +1,39 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use instant::Duration;
+use winit::event::WindowEvent;
+use winit_runtime::{
+    spawn_ui_task,
+    test::{inject_window_event, run_headless, window_id},
+    timer::wait,
+    window,
+};
+
+fn main() {
+    // Run runtime without display server
+    run_headless(async {
+        spawn_ui_task(async {
+            wait(Duration::from_millis(100)).await;
+
+            // Simulate close button click
+            inject_window_event(window_id(1), WindowEvent::CloseRequested);
+        })
+        .detach();
+
+        window()
+            .once(|(id, event), _| {
+                if let WindowEvent::CloseRequested = event {
+                    Some(id)
+                } else {
+                    None
+                }
+            })
+            .await;
+
+        println!("Synthetic close request received");
+    });
+}
@@ -0,0 +1,52 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Browser example, build with `wasm-bindgen` for `wasm32-unknown-unknown` target
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    use instant::Duration;
+    use winit::{
+        event::{ElementState, WindowEvent},
+        platform::web::WindowAttributesExtWebSys,
+        window::Window,
+    };
+    use winit_runtime::{timer::wait, window, window::create_when_resumed};
+
+    fn log(message: &str) {
+        web_sys::console::log_1(&message.into());
+    }
+
+    winit_runtime::run_web(async {
+        // create canvas appended to document body once resumed
+        let _window = create_when_resumed(Window::default_attributes().with_append(true))
+            .await
+            .unwrap();
+
+        // timer is driven by browser's event loop
+        wait(Duration::from_secs(1)).await;
+        log("Click the canvas");
+
+        window()
+            .once(|(_, event), _| match event {
+                WindowEvent::MouseInput {
+                    state: ElementState::Pressed,
+                    ..
+                } => Some(()),
+
+                _ => None,
+            })
+            .await;
+
+        log("Clicked!");
+    })
+    .unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    println!("This example only runs on wasm32-unknown-unknown target");
+}
@@ -4,17 +4,15 @@
  * Copyright (c) storycraft. Licensed under the MIT Licence.
  */
 
-use winit::event::WindowEvent;
-use winit_runtime::{create_window, resumed, window};
+use winit::{event::WindowEvent, window::Window};
+use winit_runtime::{window, window::create_when_resumed};
 
 fn main() {
     winit_runtime::run(async {
-        // wait for next resume event
-        let _window = resumed()
-            .once(|_, _|
-                // create window, on resume event
-                Some(create_window().unwrap()))
-            .await;
+        // create window once resumed
+        let _window = create_when_resumed(Window::default_attributes())
+            .await
+            .unwrap();
 
         window()
             .once(|(_, event), _| {
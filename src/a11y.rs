@@ -0,0 +1,51 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Screen reader announcements
+//!
+//! Announcements are delivered on runtime thread through [`announcement`] event source.
+//! With `accesskit` feature, they are also spoken through live region of every window attached with `accesskit::attach`.
+//! Without it, announcement is only an event hook for custom accessibility backends, and nothing is spoken.
+
+use crate::{emit, spawn_ui_task};
+
+/// Announcement priority, similar to aria-live politeness
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Priority {
+    /// Announce after current speech
+    #[default]
+    Polite,
+
+    /// Interrupt current speech
+    Assertive,
+}
+
+/// Live region style announcement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Announcement {
+    pub text: String,
+    pub priority: Priority,
+}
+
+define_event!(pub announcement: &Announcement);
+
+/// Make screen reader announcement
+///
+/// Can be called on any thread, announcement is routed to runtime thread. See module documentation for how it is delivered.
+pub fn announce(text: impl Into<String>, priority: Priority) {
+    let announcement = Announcement {
+        text: text.into(),
+        priority,
+    };
+
+    spawn_ui_task(async move {
+        emit!(self::announcement(), &announcement);
+
+        #[cfg(feature = "accesskit")]
+        crate::accesskit::announce(&announcement);
+    })
+    .detach();
+}
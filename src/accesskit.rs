@@ -0,0 +1,265 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Accessibility integration using AccessKit
+//!
+//! Runtime owns an adapter per attached window and forwards window events to it before they are dispatched.
+//! Action requests from assistive technologies are emitted on [`accessibility`] event source.
+//!
+//! Runtime wraps tree of the app in its own root node, holding live region node used by [`announce`](crate::a11y::announce).
+//! So node ids [`ROOT_ID`] and [`LIVE_REGION_ID`] are reserved and must not be used by the app.
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::{Arc, Weak},
+};
+
+use accesskit::{
+    ActionHandler, ActionRequest, ActivationHandler, DeactivationHandler, Live, NodeBuilder,
+    NodeId, Role, TreeUpdate,
+};
+use accesskit_winit::Adapter;
+use parking_lot::Mutex;
+use winit::{
+    event::WindowEvent,
+    window::{Window, WindowId},
+};
+
+use crate::{
+    a11y::{Announcement, Priority},
+    emit,
+    executor::executor_handle,
+    spawn_ui_task,
+};
+
+pub use accesskit;
+
+/// Action request of assistive technologies to the window
+#[derive(Debug, Clone)]
+pub struct ActionRequestEvent {
+    pub window_id: WindowId,
+    pub request: ActionRequest,
+}
+
+define_event!(
+    /// Action requested by assistive technologies
+    pub accessibility: &ActionRequestEvent
+);
+
+/// Id of root node added by runtime, parent of root node of the app
+pub const ROOT_ID: NodeId = NodeId(u64::MAX);
+
+/// Id of live region node for announcements
+pub const LIVE_REGION_ID: NodeId = NodeId(u64::MAX - 1);
+
+thread_local! {
+    static ADAPTERS: RefCell<HashMap<WindowId, Attached>> = RefCell::new(HashMap::new());
+}
+
+struct Attached {
+    window: Weak<Window>,
+    adapter: Adapter,
+    tree: Arc<Mutex<TreeState>>,
+}
+
+/// Runtime side state of the tree, shared with activation handler which can be called on any thread
+#[derive(Debug)]
+struct TreeState {
+    /// Root node of the app
+    root: Option<NodeId>,
+
+    /// Last focus given by the app, as focus must be given with every update
+    focus: NodeId,
+
+    /// Text of live region
+    announcement: Option<(String, Priority)>,
+}
+
+impl TreeState {
+    fn wrap(&mut self, mut update: TreeUpdate) -> TreeUpdate {
+        self.focus = update.focus;
+
+        if let Some(ref mut tree) = update.tree {
+            let root = tree.root;
+            self.root = Some(root);
+
+            tree.root = ROOT_ID;
+
+            let mut root_node = NodeBuilder::new(Role::Window);
+            root_node.set_children(vec![root, LIVE_REGION_ID]);
+            update.nodes.push((ROOT_ID, root_node.build()));
+            update.nodes.push((LIVE_REGION_ID, self.live_region()));
+        }
+
+        update
+    }
+
+    fn live_region(&self) -> accesskit::Node {
+        let mut node = NodeBuilder::new(Role::Label);
+
+        match self.announcement {
+            Some((ref text, priority)) => {
+                node.set_name(text.clone());
+                node.set_live(match priority {
+                    Priority::Polite => Live::Polite,
+                    Priority::Assertive => Live::Assertive,
+                });
+            }
+
+            None => node.set_live(Live::Polite),
+        }
+
+        node.build()
+    }
+
+    fn live_region_update(&self) -> TreeUpdate {
+        TreeUpdate {
+            nodes: vec![(LIVE_REGION_ID, self.live_region())],
+            tree: None,
+            focus: self.focus,
+        }
+    }
+}
+
+/// Attach AccessKit adapter to the window, with `initial` tree provided once assistive technology is active
+///
+/// It must be called before the window is shown for the first time, so create the window invisible and show it after.
+/// `initial` is called again if assistive technology is activated again after deactivation.
+/// Adapter is detached once window is destroyed. Must be called on runtime thread.
+pub fn attach(window: &Arc<Window>, initial: impl FnMut() -> TreeUpdate + Send + 'static) {
+    let window_id = window.id();
+    let tree = Arc::new(Mutex::new(TreeState {
+        root: None,
+        focus: ROOT_ID,
+        announcement: None,
+    }));
+
+    let adapter = Adapter::with_direct_handlers(
+        window,
+        InitialTree {
+            initial,
+            tree: tree.clone(),
+        },
+        RuntimeActionHandler { window_id },
+        Deactivation,
+    );
+
+    ADAPTERS.with(|adapters| {
+        adapters.borrow_mut().insert(
+            window_id,
+            Attached {
+                window: Arc::downgrade(window),
+                adapter,
+                tree,
+            },
+        )
+    });
+}
+
+/// Update accessibility tree of the window, if assistive technology is active
+///
+/// Can be called on any thread, update is routed to runtime thread.
+pub fn update_tree(window_id: WindowId, update: TreeUpdate) {
+    if executor_handle().is_runtime_thread() {
+        apply_update(window_id, update);
+    } else {
+        spawn_ui_task(async move { apply_update(window_id, update) }).detach();
+    }
+}
+
+fn apply_update(window_id: WindowId, update: TreeUpdate) {
+    ADAPTERS.with(|adapters| {
+        if let Some(attached) = adapters.borrow_mut().get_mut(&window_id) {
+            let tree = &attached.tree;
+            attached
+                .adapter
+                .update_if_active(move || tree.lock().wrap(update));
+        }
+    });
+}
+
+/// Announce through live region of every attached window, called on runtime thread
+pub(crate) fn announce(announcement: &Announcement) {
+    ADAPTERS.with(|adapters| {
+        for attached in adapters.borrow_mut().values_mut() {
+            let mut tree = attached.tree.lock();
+
+            // Clear first, so same text is announced again
+            if tree
+                .announcement
+                .as_ref()
+                .is_some_and(|(text, _)| *text == announcement.text)
+            {
+                tree.announcement = None;
+                attached
+                    .adapter
+                    .update_if_active(|| tree.live_region_update());
+            }
+
+            tree.announcement = Some((announcement.text.clone(), announcement.priority));
+            attached
+                .adapter
+                .update_if_active(|| tree.live_region_update());
+        }
+    });
+}
+
+/// Forward window event to adapter of the window
+pub(crate) fn process_event(window_id: WindowId, event: &WindowEvent) {
+    ADAPTERS.with(|adapters| {
+        let mut adapters = adapters.borrow_mut();
+
+        if let WindowEvent::Destroyed = event {
+            adapters.remove(&window_id);
+            return;
+        }
+
+        if let Some(attached) = adapters.get_mut(&window_id) {
+            if let Some(window) = attached.window.upgrade() {
+                attached.adapter.process_event(&window, event);
+            }
+        }
+    });
+}
+
+struct InitialTree<F> {
+    initial: F,
+    tree: Arc<Mutex<TreeState>>,
+}
+
+impl<F: FnMut() -> TreeUpdate> ActivationHandler for InitialTree<F> {
+    fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+        let update = (self.initial)();
+        Some(self.tree.lock().wrap(update))
+    }
+}
+
+/// Tree is provided by `initial` again on next activation, so nothing to drop
+struct Deactivation;
+
+impl DeactivationHandler for Deactivation {
+    fn deactivate_accessibility(&mut self) {}
+}
+
+/// Action handler routing requests to runtime thread, as it can be called on any thread
+struct RuntimeActionHandler {
+    window_id: WindowId,
+}
+
+impl ActionHandler for RuntimeActionHandler {
+    fn do_action(&mut self, request: ActionRequest) {
+        let event = ActionRequestEvent {
+            window_id: self.window_id,
+            request,
+        };
+
+        spawn_ui_task(async move {
+            emit!(accessibility(), &event);
+        })
+        .detach();
+    }
+}
@@ -0,0 +1,46 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Android entrypoint and surface lifecycle helpers
+//!
+//! Native window is only available between [`Event::Resumed`] and [`Event::Suspended`],
+//! so every surfaces must be created on resume and destroyed on suspend.
+//!
+//! [`Event::Resumed`]: winit::event::Event::Resumed
+//! [`Event::Suspended`]: winit::event::Event::Suspended
+
+use futures_lite::Future;
+use winit::{error::EventLoopError, platform::android::EventLoopBuilderExtAndroid};
+
+pub use winit::platform::android::activity::AndroidApp;
+
+use crate::{executor::RuntimeBuilder, resumed, suspended};
+
+/// Entrypoint for runtime on android, call it from `android_main`
+///
+/// ```ignore
+/// #[no_mangle]
+/// fn android_main(app: AndroidApp) {
+///     winit_runtime::android::run_android(app, async { ... }).unwrap();
+/// }
+/// ```
+pub fn run_android(app: AndroidApp, main: impl Future<Output = ()>) -> Result<(), EventLoopError> {
+    RuntimeBuilder::new()
+        .event_loop(|builder| {
+            builder.with_android_app(app);
+        })
+        .run(main)
+}
+
+/// Wait until native window becomes available, surfaces must be recreated after this
+pub async fn surface_created() {
+    resumed().once(|_, _| Some(())).await;
+}
+
+/// Wait until native window is destroyed, surfaces must be dropped after this
+pub async fn surface_destroyed() {
+    suspended().once(|_, _| Some(())).await;
+}
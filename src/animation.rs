@@ -0,0 +1,207 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Frame driven animations, written as simple awaits
+//!
+//! ```ignore
+//! // Fade in over 300ms, updated on every redraw of the window
+//! animate_with(Frames::window(&window), Duration::from_millis(300), Easing::CubicOut, |t| {
+//!     state.opacity = t;
+//! })
+//! .await;
+//! ```
+
+use instant::{Duration, Instant};
+use winit::{event::WindowEvent, window::Window};
+
+use crate::timer;
+
+/// Frame interval of [`Frames::timer`]
+pub const FRAME_INTERVAL: Duration = Duration::from_micros(16_667);
+
+/// Source of animation frames
+#[derive(Debug, Clone, Copy)]
+pub struct Frames<'a> {
+    window: Option<&'a Window>,
+}
+
+impl<'a> Frames<'a> {
+    /// Frames of fixed [`FRAME_INTERVAL`] using runtime timer
+    pub const fn timer() -> Self {
+        Self { window: None }
+    }
+
+    /// Frames following redraws of the window
+    ///
+    /// Redraw is requested every frame, and frame starts once [`WindowEvent::RedrawRequested`] is dispatched.
+    /// So animation progresses at rate window is redrawn, which follows [`PowerProfile`](crate::config::PowerProfile) and occlusion.
+    pub const fn window(window: &'a Window) -> Self {
+        Self {
+            window: Some(window),
+        }
+    }
+
+    /// Wait for next frame, returns its time
+    pub async fn next(&mut self) -> Instant {
+        match self.window {
+            Some(window) => {
+                let id = window.id();
+                window.request_redraw();
+
+                let _ = crate::window()
+                    .wait_for(
+                        |(window_id, event)| {
+                            (window_id == id && matches!(event, WindowEvent::RedrawRequested))
+                                .then_some(())
+                        },
+                        None,
+                    )
+                    .await;
+            }
+
+            None => {
+                timer::wait(FRAME_INTERVAL).await;
+            }
+        }
+
+        timer::now()
+    }
+}
+
+impl Default for Frames<'_> {
+    fn default() -> Self {
+        Self::timer()
+    }
+}
+
+/// Easing curve mapping linear progress to eased progress, both in `0.0..=1.0`
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    Custom(fn(f64) -> f64),
+}
+
+impl Easing {
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t).powi(2),
+            Easing::QuadInOut if t < 0.5 => 2.0 * t * t,
+            Easing::QuadInOut => 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0,
+            Easing::CubicIn => t.powi(3),
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut if t < 0.5 => 4.0 * t.powi(3),
+            Easing::CubicInOut => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0,
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// Animate over `duration` on [`Frames::timer`]
+///
+/// See [`animate_with`]
+pub async fn animate(duration: Duration, easing: Easing, f: impl FnMut(f64)) {
+    animate_with(Frames::timer(), duration, easing, f).await
+}
+
+/// Animate over `duration`, calling `f` with eased progress every frame
+///
+/// Progress is computed from frame time, so frames skipped do not slow animation down. Last call is always with `1.0`.
+pub async fn animate_with(
+    mut frames: Frames<'_>,
+    duration: Duration,
+    easing: Easing,
+    mut f: impl FnMut(f64),
+) {
+    let start = timer::now();
+
+    loop {
+        let elapsed = frames.next().await - start;
+        if elapsed >= duration {
+            break;
+        }
+
+        f(easing.apply(elapsed.as_secs_f64() / duration.as_secs_f64()));
+    }
+
+    f(easing.apply(1.0));
+}
+
+/// Damped spring physics parameters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    pub stiffness: f64,
+    pub damping: f64,
+    pub mass: f64,
+}
+
+impl Spring {
+    /// Responsive spring without bounce
+    pub const DEFAULT: Self = Self {
+        stiffness: 170.0,
+        damping: 26.0,
+        mass: 1.0,
+    };
+
+    /// Spring with noticeable bounce
+    pub const BOUNCY: Self = Self {
+        stiffness: 180.0,
+        damping: 12.0,
+        mass: 1.0,
+    };
+
+    /// Animate value from `from` to `to` until the spring settles, calling `f` with value every frame
+    ///
+    /// Last call is always with `to`.
+    pub async fn animate(self, mut frames: Frames<'_>, from: f64, to: f64, mut f: impl FnMut(f64)) {
+        // Physics step, frames longer than this are integrated in multiple steps
+        const STEP: f64 = 1.0 / 240.0;
+
+        let threshold = (to - from).abs().max(1.0) * 0.001;
+        let (mut position, mut velocity) = (from, 0.0);
+        let mut last = timer::now();
+
+        loop {
+            let now = frames.next().await;
+            let mut remaining = (now - last).as_secs_f64();
+            last = now;
+
+            while remaining > 0.0 {
+                let dt = remaining.min(STEP);
+                remaining -= dt;
+
+                let force = -self.stiffness * (position - to) - self.damping * velocity;
+                velocity += force / self.mass * dt;
+                position += velocity * dt;
+            }
+
+            if (position - to).abs() < threshold && velocity.abs() < threshold {
+                break;
+            }
+
+            f(position);
+        }
+
+        f(to);
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
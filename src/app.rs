@@ -0,0 +1,166 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! High level application facade
+
+use std::{cell::Cell, error::Error, fmt};
+
+use futures_lite::{future, Future};
+use winit::{
+    error::{EventLoopError, OsError},
+    event::WindowEvent,
+    event_loop::EventLoopBuilder,
+    window::{Window, WindowAttributes},
+};
+
+use crate::{executor::event::ExecutorEvent, run_with, window, window::create_when_resumed};
+
+type BuilderConfig = Box<dyn FnOnce(&mut EventLoopBuilder<ExecutorEvent>)>;
+
+/// Application with a main window
+///
+/// Wires event loop creation, window creation on first resume and closing together.
+/// ```ignore
+/// App::new().run(|window| async move { ... }).unwrap();
+/// ```
+pub struct App {
+    window: WindowAttributes,
+    builder_config: Option<BuilderConfig>,
+    exit_on_close: bool,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self {
+            window: Window::default_attributes(),
+            builder_config: None,
+            exit_on_close: true,
+        }
+    }
+
+    /// Set main window configuration
+    pub fn window(mut self, attributes: WindowAttributes) -> Self {
+        self.window = attributes;
+        self
+    }
+
+    /// Customize event loop before it is built
+    ///
+    /// See [`run_with`]
+    pub fn event_loop(
+        mut self,
+        config: impl FnOnce(&mut EventLoopBuilder<ExecutorEvent>) + 'static,
+    ) -> Self {
+        self.builder_config = Some(Box::new(config));
+        self
+    }
+
+    /// Exit when close is requested on main window. Default is `true`.
+    pub fn exit_on_close(mut self, exit_on_close: bool) -> Self {
+        self.exit_on_close = exit_on_close;
+        self
+    }
+
+    /// Run application, creating main window on first resume and passing it to `main`
+    ///
+    /// Exits with [`AppError::Window`] if main window cannot be created.
+    pub fn run<F, Fut>(self, main: F) -> Result<(), AppError>
+    where
+        F: FnOnce(Window) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let Self {
+            window: attributes,
+            builder_config,
+            exit_on_close,
+        } = self;
+
+        let window_error = Cell::new(None);
+
+        run_with(
+            move |event_loop| {
+                if let Some(config) = builder_config {
+                    config(event_loop);
+                }
+            },
+            async {
+                let main_window = match create_when_resumed(attributes).await {
+                    Ok(window) => window,
+                    Err(err) => {
+                        window_error.set(Some(err));
+                        return;
+                    }
+                };
+
+                let id = main_window.id();
+                let main = main(main_window);
+
+                if !exit_on_close {
+                    return main.await;
+                }
+
+                future::or(main, async move {
+                    window()
+                        .once(|(window_id, event), _| {
+                            if window_id == id && matches!(event, WindowEvent::CloseRequested) {
+                                Some(())
+                            } else {
+                                None
+                            }
+                        })
+                        .await;
+                })
+                .await
+            },
+        )?;
+
+        match window_error.into_inner() {
+            Some(err) => Err(AppError::Window(err)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error of [`App::run`]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AppError {
+    /// Event loop failed
+    EventLoop(EventLoopError),
+
+    /// Main window cannot be created
+    Window(OsError),
+}
+
+impl From<EventLoopError> for AppError {
+    fn from(err: EventLoopError) -> Self {
+        AppError::EventLoop(err)
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::EventLoop(err) => write!(f, "event loop error: {err}"),
+            AppError::Window(err) => write!(f, "failed to create main window: {err}"),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::EventLoop(err) => Some(err),
+            AppError::Window(err) => Some(err),
+        }
+    }
+}
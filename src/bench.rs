@@ -0,0 +1,196 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Benchmark harness for event handling code, running on headless runtime
+//!
+//! Measures how long each synthetic event takes from injection until it reaches listeners.
+
+use std::{mem, pin::pin, sync::Arc};
+
+use futures_lite::{future::poll_once, Future};
+use instant::{Duration, Instant};
+use parking_lot::Mutex;
+use winit::{dpi::PhysicalPosition, event::WindowEvent, window::WindowId};
+
+use crate::{
+    executor::{inject_window_event, yield_now},
+    spawn_local_ui_task,
+    test::{device_id, run_headless, window_id},
+    timer::{now, wait, wait_until},
+    window,
+};
+
+/// Synthetic workload configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Workload {
+    /// Number of fake windows receiving events
+    pub windows: usize,
+
+    /// Number of events dispatched per second
+    pub events_per_sec: u32,
+
+    /// Number of concurrently running 60Hz timers
+    pub timers: usize,
+
+    /// Benchmark duration
+    pub duration: Duration,
+}
+
+impl Default for Workload {
+    fn default() -> Self {
+        Self {
+            windows: 1,
+            events_per_sec: 1000,
+            timers: 0,
+            duration: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Event dispatch latency report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchReport {
+    pub samples: usize,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl BenchReport {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+
+        let percentile = |p: usize| {
+            samples
+                .get((samples.len() * p / 100).min(samples.len().saturating_sub(1)))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        Self {
+            samples: samples.len(),
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: samples.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Run benchmark on headless runtime
+///
+/// `app` is spawned before the workload starts with fake window ids, to register event listeners under test.
+/// Events are injected through headless event loop at a steady rate, and each sample measures from injection until
+/// event is dispatched to listeners of `app`, including time spent in runtime's dispatcher.
+/// Events which `app` stops propagating are not sampled.
+/// Like [`run_headless`], only one runtime can run at a time.
+pub fn run<F, Fut>(workload: Workload, app: F) -> BenchReport
+where
+    F: FnOnce(Vec<WindowId>) -> Fut,
+    Fut: Future<Output = ()> + 'static,
+{
+    let mut report = None;
+
+    run_headless(async {
+        let windows = (1..=workload.windows as u64)
+            .map(window_id)
+            .collect::<Vec<_>>();
+
+        let _app = spawn_local_ui_task(app(windows.clone()));
+
+        let _timers = (0..workload.timers)
+            .map(|_| {
+                spawn_local_ui_task(async {
+                    loop {
+                        wait(Duration::from_micros(16_667)).await;
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Let app register listeners
+        futures_lite::future::yield_now().await;
+
+        if windows.is_empty() || workload.events_per_sec == 0 {
+            report = Some(BenchReport::from_samples(Vec::new()));
+            return;
+        }
+
+        let injected = Arc::new(Mutex::new(Vec::<Instant>::new()));
+        let samples = Arc::new(Mutex::new(Vec::new()));
+
+        // Registered after listeners of app, so it sees events once they are dispatched to them
+        let mut sampler = pin!(window().on({
+            let injected = injected.clone();
+            let samples = samples.clone();
+
+            move |(_, event), _| {
+                if let WindowEvent::CursorMoved { position, .. } = event {
+                    let injected = injected.lock();
+                    if let Some(start) = injected.get(decode_index(*position)) {
+                        samples.lock().push(start.elapsed());
+                    }
+                }
+            }
+        }));
+        let _ = poll_once(sampler.as_mut()).await;
+
+        let interval = Duration::from_secs(1) / workload.events_per_sec;
+        let start = now();
+        let mut count = 0_usize;
+
+        loop {
+            let deadline = start + interval * count as u32;
+            if deadline.saturating_duration_since(start) >= workload.duration {
+                break;
+            }
+
+            if deadline > now() {
+                wait_until(deadline).await;
+            }
+
+            injected.lock().push(Instant::now());
+            inject_window_event(
+                windows[count % windows.len()],
+                WindowEvent::CursorMoved {
+                    device_id: device_id(),
+                    position: encode_index(count),
+                },
+            );
+
+            count += 1;
+
+            // Let injected events dispatch while behind schedule
+            if count % 64 == 0 {
+                yield_now().await;
+            }
+        }
+
+        // Wait for remaining events to be dispatched, some may never be sampled
+        for _ in 0..16 {
+            if samples.lock().len() >= count {
+                break;
+            }
+
+            yield_now().await;
+        }
+
+        let samples = mem::take(&mut *samples.lock());
+        report = Some(BenchReport::from_samples(samples));
+    });
+
+    report.unwrap()
+}
+
+/// Encode event index into cursor position inside of 1920 pixels wide screen
+fn encode_index(index: usize) -> PhysicalPosition<f64> {
+    PhysicalPosition::new((index % 1920) as f64, (index / 1920) as f64)
+}
+
+fn decode_index(position: PhysicalPosition<f64>) -> usize {
+    position.y as usize * 1920 + position.x as usize
+}
@@ -0,0 +1,71 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Typed event bus for application events
+//!
+//! Each event type has its own [`topic`], so components can broadcast events without declaring sources up front.
+//! Use [`define_event`](crate::define_event) instead if event source can be declared statically.
+//!
+//! ```ignore
+//! struct ThemeChanged(Theme);
+//!
+//! spawn_ui_task(bus::subscribe(|ThemeChanged(theme): &ThemeChanged, _| apply(theme))).detach();
+//!
+//! bus::publish(&ThemeChanged(Theme::Dark));
+//! ```
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+use higher_kinded_types::ForLt;
+use parking_lot::{const_mutex, Mutex};
+
+use crate::{
+    emit,
+    event::{ControlFlow, EventFnFuture},
+    EventSource,
+};
+
+type Topics = HashMap<TypeId, &'static (dyn Any + Send + Sync)>;
+
+static TOPICS: Mutex<Option<Topics>> = const_mutex(None);
+
+/// Get event source of the event type, created on first access
+pub fn topic<T: Send + Sync + 'static>() -> &'static EventSource!(&T) {
+    let mut topics = TOPICS.lock();
+
+    let topic = *topics
+        .get_or_insert_with(HashMap::new)
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| {
+            let source: &'static EventSource!(&T) =
+                Box::leak(Box::new(crate::event::EventSource::new()));
+
+            source
+        });
+
+    topic
+        .downcast_ref()
+        .expect("Topic is registered with different type")
+}
+
+/// Broadcast event to every listeners of its topic
+pub fn publish<T: Send + Sync + 'static>(event: &T) {
+    emit!(topic::<T>(), event);
+}
+
+/// Listen events of the type
+///
+/// See [`EventSource::on`](crate::event::EventSource::on)
+pub fn subscribe<T, F>(listener: F) -> EventFnFuture<'static, F, ForLt!(&T)>
+where
+    T: Send + Sync + 'static,
+    F: FnMut(&T, &mut ControlFlow) + Send,
+{
+    topic::<T>().on(listener)
+}
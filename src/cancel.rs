@@ -0,0 +1,123 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Cooperative cancellation
+//!
+//! Cancelling a token wakes every task waiting on it, including tasks on runtime thread while event loop is sleeping.
+//! See [`ExecutorHandle::exit_token`] and [`ExecutorHandle::suspend_token`] for tokens cancelled by runtime.
+//!
+//! [`ExecutorHandle::exit_token`]: crate::executor::handle::ExecutorHandle::exit_token
+//! [`ExecutorHandle::suspend_token`]: crate::executor::handle::ExecutorHandle::suspend_token
+
+use std::{
+    fmt,
+    sync::{Arc, Weak},
+};
+
+use futures_intrusive::sync::ManualResetEvent;
+use futures_lite::{future, Future};
+use parking_lot::Mutex;
+
+/// Token signalling cancellation to tasks, cheap to clone
+///
+/// Cancelling a token cancels every child tokens too.
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<TokenInner>,
+}
+
+struct TokenInner {
+    event: ManualResetEvent,
+    children: Mutex<Vec<Weak<TokenInner>>>,
+}
+
+impl TokenInner {
+    fn cancel(&self) {
+        if self.event.is_set() {
+            return;
+        }
+        self.event.set();
+
+        for child in self.children.lock().drain(..) {
+            if let Some(child) = child.upgrade() {
+                child.cancel();
+            }
+        }
+    }
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(TokenInner {
+                event: ManualResetEvent::new(false),
+                children: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Create child token cancelled together with this token
+    ///
+    /// Cancelling child token does not affect this token. Child of cancelled token is cancelled already.
+    pub fn child(&self) -> Self {
+        let child = Self::new();
+
+        {
+            let mut children = self.inner.children.lock();
+
+            if !self.is_cancelled() {
+                children.retain(|child| child.strong_count() > 0);
+                children.push(Arc::downgrade(&child.inner));
+                return child;
+            }
+        }
+
+        child.cancel();
+        child
+    }
+
+    /// Cancel this token and every child tokens
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.event.is_set()
+    }
+
+    /// Wait until token is cancelled
+    pub async fn cancelled(&self) {
+        self.inner.event.wait().await
+    }
+
+    /// Run future until it completes or token is cancelled, returns [`None`] if cancelled
+    ///
+    /// Cancellation is checked before polling the future, so it is never polled after token is cancelled.
+    pub async fn run_until_cancelled<Fut: Future>(&self, fut: Fut) -> Option<Fut::Output> {
+        future::or(
+            async {
+                self.cancelled().await;
+                None
+            },
+            async { Some(fut.await) },
+        )
+        .await
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Software rendering canvas using softbuffer
+
+use std::{
+    cell::{Cell, RefCell},
+    num::NonZeroU32,
+    sync::Arc,
+};
+
+use softbuffer::{Context, SoftBufferError, Surface};
+use winit::{dpi::PhysicalSize, event::WindowEvent, window::Window};
+
+use crate::window;
+
+/// CPU accessible frame buffer of a window
+///
+/// Pixels are `0RGB` packed [`u32`] values in row major order.
+#[derive(Debug)]
+pub struct Canvas {
+    _context: Context<Arc<Window>>,
+    surface: RefCell<Surface<Arc<Window>, Arc<Window>>>,
+    size: Cell<Option<PhysicalSize<u32>>>,
+}
+
+impl Canvas {
+    pub fn new(window: Arc<Window>) -> Result<Self, SoftBufferError> {
+        let context = Context::new(window.clone())?;
+        let surface = Surface::new(&context, window)?;
+
+        Ok(Self {
+            _context: context,
+            surface: RefCell::new(surface),
+            size: Cell::new(None),
+        })
+    }
+
+    pub fn window(&self) -> Arc<Window> {
+        self.surface.borrow().window().clone()
+    }
+
+    /// Draw frame with current window size and present it
+    ///
+    /// Does nothing if window is minimized.
+    pub fn present(
+        &self,
+        draw: impl FnOnce(PhysicalSize<u32>, &mut [u32]),
+    ) -> Result<(), SoftBufferError> {
+        let mut surface = self.surface.borrow_mut();
+
+        let size = surface.window().inner_size();
+        if !self.resize(&mut surface, size)? {
+            return Ok(());
+        }
+
+        let mut buffer = surface.buffer_mut()?;
+        draw(size, &mut buffer);
+        buffer.present()
+    }
+
+    /// Resize buffer, returns `false` if size is zero
+    fn resize(
+        &self,
+        surface: &mut Surface<Arc<Window>, Arc<Window>>,
+        size: PhysicalSize<u32>,
+    ) -> Result<bool, SoftBufferError> {
+        let (Some(width), Some(height)) =
+            (NonZeroU32::new(size.width), NonZeroU32::new(size.height))
+        else {
+            return Ok(false);
+        };
+
+        if self.size.get() != Some(size) {
+            surface.resize(width, height)?;
+            self.size.set(Some(size));
+        }
+
+        Ok(true)
+    }
+
+    /// Resize buffer and request redraw every time window is resized
+    pub async fn maintain(&self) -> ! {
+        let window = self.window();
+        let id = window.id();
+
+        loop {
+            let Some(size) = self::window()
+                .once(move |(window_id, event), _| match event {
+                    WindowEvent::Resized(size) if window_id == id => Some(*size),
+                    _ => None,
+                })
+                .await
+            else {
+                continue;
+            };
+
+            if self.resize(&mut self.surface.borrow_mut(), size).is_ok() {
+                window.request_redraw();
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Clipboard access using arboard
+//!
+//! Every operation runs on runtime thread, so it can be called from any thread.
+//! Clipboard is kept alive on runtime thread, for platforms serving clipboard contents from owning process.
+
+use std::cell::RefCell;
+
+use arboard::{Clipboard, Error};
+
+use crate::spawn_ui_task;
+
+thread_local! {
+    static CLIPBOARD: RefCell<Option<Clipboard>> = const { RefCell::new(None) };
+}
+
+fn with_clipboard<R>(f: impl FnOnce(&mut Clipboard) -> Result<R, Error>) -> Result<R, Error> {
+    CLIPBOARD.with(|clipboard| {
+        let mut clipboard = clipboard.borrow_mut();
+
+        let clipboard = match *clipboard {
+            Some(ref mut clipboard) => clipboard,
+            None => clipboard.insert(Clipboard::new()?),
+        };
+
+        f(clipboard)
+    })
+}
+
+/// Read text from clipboard
+pub async fn read_text() -> Result<String, Error> {
+    spawn_ui_task(async { with_clipboard(|clipboard| clipboard.get_text()) }).await
+}
+
+/// Write text to clipboard
+pub async fn write_text(text: impl Into<String>) -> Result<(), Error> {
+    let text = text.into();
+
+    spawn_ui_task(async move { with_clipboard(move |clipboard| clipboard.set_text(text)) }).await
+}
+
+/// Clear clipboard
+pub async fn clear() -> Result<(), Error> {
+    spawn_ui_task(async { with_clipboard(|clipboard| clipboard.clear()) }).await
+}
@@ -0,0 +1,440 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Runtime configuration which can be changed while running
+//!
+//! Every change is notified through [`changed`] event source on runtime thread.
+
+use std::{fmt, mem, ptr};
+
+use instant::Duration;
+use parking_lot::Mutex;
+
+use crate::{
+    emit,
+    executor::{try_executor_handle, RuntimeError},
+    timer::{Clock, SystemClock},
+};
+
+/// Event loop behaviour when there is no pending event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RedrawPolicy {
+    /// Sleep until next event or timer
+    #[default]
+    OnDemand,
+
+    /// Keep polling event loop, for apps redrawing every iteration
+    Continuous,
+}
+
+/// Order of dispatching events and running woken tasks within an event loop iteration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DispatchOrder {
+    /// Dispatch every pending input event before running woken tasks, for lower input latency
+    ///
+    /// Tasks still run right after lifecycle and redraw events.
+    #[default]
+    InputFirst,
+
+    /// Run woken tasks after every event
+    Interleaved,
+}
+
+/// Power usage preference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PowerProfile {
+    #[default]
+    Balanced,
+
+    /// Prefer lower power usage. [`RedrawPolicy::Continuous`] is ignored on this profile.
+    ///
+    /// Timers are coalesced, occluded windows are not redrawn and unfocused windows are redrawn at limited rate.
+    PowerSaving,
+}
+
+impl PowerProfile {
+    /// Minimum timer slack of the profile, larger while no window is focused
+    pub const fn timer_slack(self, focused: bool) -> Duration {
+        match self {
+            PowerProfile::Balanced => Duration::ZERO,
+            PowerProfile::PowerSaving if focused => Duration::from_millis(15),
+            PowerProfile::PowerSaving => Duration::from_millis(100),
+        }
+    }
+
+    /// Minimum interval between redraws of unfocused window, [`None`] if unlimited
+    pub const fn background_frame_interval(self) -> Option<Duration> {
+        match self {
+            PowerProfile::Balanced => None,
+            PowerProfile::PowerSaving => Some(Duration::from_millis(100)),
+        }
+    }
+}
+
+/// Limit of task polling per event loop iteration
+///
+/// Once exhausted, remaining tasks are deferred to next iteration so pending events can be handled first.
+/// Unlimited by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PollBudget {
+    /// Maximum number of task polls
+    pub tasks: Option<usize>,
+
+    /// Maximum time spent on polling tasks
+    pub time: Option<Duration>,
+}
+
+impl From<usize> for PollBudget {
+    /// Budget limited by number of task polls
+    fn from(tasks: usize) -> Self {
+        Self {
+            tasks: Some(tasks),
+            time: None,
+        }
+    }
+}
+
+/// Changed configuration with its new value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConfigChange {
+    RedrawPolicy(RedrawPolicy),
+    PowerProfile(PowerProfile),
+    ExitOnLastWindowClose(bool),
+    Background(bool),
+    PollBudget(PollBudget),
+    TimerSlack(Duration),
+    PauseOccluded(bool),
+    DispatchOrder(DispatchOrder),
+    TeardownDeadline(Option<Duration>),
+    ReportLeakedTasks(bool),
+    Metrics(bool),
+    StallThreshold(Option<Duration>),
+}
+
+define_event!(pub changed: &ConfigChange);
+
+#[derive(Debug, Clone, Copy)]
+struct Settings {
+    redraw_policy: RedrawPolicy,
+    power_profile: PowerProfile,
+    exit_on_last_window_close: bool,
+    background: bool,
+    poll_budget: PollBudget,
+    timer_slack: Duration,
+    pause_occluded: bool,
+    dispatch_order: DispatchOrder,
+    teardown_deadline: Option<Duration>,
+    report_leaked_tasks: bool,
+    metrics: bool,
+    stall_threshold: Option<Duration>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            redraw_policy: RedrawPolicy::default(),
+            power_profile: PowerProfile::default(),
+            exit_on_last_window_close: false,
+            background: false,
+            poll_budget: PollBudget::default(),
+            timer_slack: Duration::ZERO,
+            pause_occluded: false,
+            dispatch_order: DispatchOrder::default(),
+            teardown_deadline: None,
+            report_leaked_tasks: false,
+            metrics: true,
+            stall_threshold: None,
+        }
+    }
+}
+
+/// Runtime configuration handle
+///
+/// See [`ExecutorHandle::config`](crate::executor::handle::ExecutorHandle::config)
+pub struct RuntimeConfig {
+    settings: Mutex<Settings>,
+    clock: &'static dyn Clock,
+}
+
+impl RuntimeConfig {
+    /// Create default configuration, for starting runtime with
+    ///
+    /// Setters can be used before starting runtime. [`changed`] events are only emitted for configuration of running runtime.
+    pub fn new() -> Self {
+        Self {
+            settings: Mutex::new(Settings::default()),
+            clock: &SystemClock,
+        }
+    }
+
+    /// Use given [`Clock`] for timers, like [`MockClock`](crate::test::MockClock) for deterministic tests
+    pub fn with_clock(mut self, clock: &'static dyn Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub(crate) fn clock(&self) -> &'static dyn Clock {
+        self.clock
+    }
+
+    pub fn redraw_policy(&self) -> RedrawPolicy {
+        self.settings.lock().redraw_policy
+    }
+
+    pub fn set_redraw_policy(&self, policy: RedrawPolicy) {
+        self.update(ConfigChange::RedrawPolicy(policy));
+    }
+
+    pub fn power_profile(&self) -> PowerProfile {
+        self.settings.lock().power_profile
+    }
+
+    pub fn set_power_profile(&self, profile: PowerProfile) {
+        self.update(ConfigChange::PowerProfile(profile));
+    }
+
+    /// Exit event loop once every windows created with [`build_window`] are destroyed. Default is `false`.
+    ///
+    /// [`build_window`]: crate::build_window
+    pub fn exit_on_last_window_close(&self) -> bool {
+        self.settings.lock().exit_on_last_window_close
+    }
+
+    pub fn set_exit_on_last_window_close(&self, exit: bool) {
+        self.update(ConfigChange::ExitOnLastWindowClose(exit));
+    }
+
+    /// Background mode, for tray icon or daemon style apps running with zero windows
+    ///
+    /// On background mode, runtime never exits on last window close and event loop always sleeps while there are no windows.
+    pub fn background(&self) -> bool {
+        self.settings.lock().background
+    }
+
+    pub fn set_background(&self, background: bool) {
+        self.update(ConfigChange::Background(background));
+    }
+
+    pub fn poll_budget(&self) -> PollBudget {
+        self.settings.lock().poll_budget
+    }
+
+    pub fn set_poll_budget(&self, budget: PollBudget) {
+        self.update(ConfigChange::PollBudget(budget));
+    }
+
+    /// Timer coalescing window, reducing wakeups on battery powered devices. Default is zero.
+    ///
+    /// Timer deadlines are delayed up to this duration, so that timers expiring close together wake event loop once.
+    pub fn timer_slack(&self) -> Duration {
+        self.settings.lock().timer_slack
+    }
+
+    pub fn set_timer_slack(&self, slack: Duration) {
+        self.update(ConfigChange::TimerSlack(slack));
+    }
+
+    /// Withhold [`WindowEvent::RedrawRequested`] of occluded windows, and stop continuous polling while every windows are occluded. Default is `false`.
+    ///
+    /// Window is redrawn once it becomes visible again.
+    ///
+    /// [`WindowEvent::RedrawRequested`]: winit::event::WindowEvent::RedrawRequested
+    pub fn pause_occluded(&self) -> bool {
+        self.settings.lock().pause_occluded
+    }
+
+    pub fn set_pause_occluded(&self, pause: bool) {
+        self.update(ConfigChange::PauseOccluded(pause));
+    }
+
+    pub fn dispatch_order(&self) -> DispatchOrder {
+        self.settings.lock().dispatch_order
+    }
+
+    pub fn set_dispatch_order(&self, order: DispatchOrder) {
+        self.update(ConfigChange::DispatchOrder(order));
+    }
+
+    /// Abort process if teardown after event loop exits takes longer than this. Default is [`None`], waiting until it finishes.
+    ///
+    /// Teardown drops pending tasks, runs [`on_exit`](crate::executor::on_exit) hooks and drops main task. Ignored on wasm.
+    pub fn teardown_deadline(&self) -> Option<Duration> {
+        self.settings.lock().teardown_deadline
+    }
+
+    pub fn set_teardown_deadline(&self, deadline: Option<Duration>) {
+        self.update(ConfigChange::TeardownDeadline(deadline));
+    }
+
+    /// Report tasks still alive on exit to [`on_after_shutdown`](crate::executor::on_after_shutdown) hook,
+    /// as [`AfterShutdown::TasksLeaked`](crate::executor::AfterShutdown::TasksLeaked) with location they are spawned. Default is `false`.
+    ///
+    /// Tasks neither completed nor cancelled by then are usually detached loops, leaking until runtime exits.
+    /// Listed tasks are dropped on teardown as usual.
+    pub fn report_leaked_tasks(&self) -> bool {
+        self.settings.lock().report_leaked_tasks
+    }
+
+    pub fn set_report_leaked_tasks(&self, report: bool) {
+        self.update(ConfigChange::ReportLeakedTasks(report));
+    }
+
+    /// Collect event dispatch latencies for `executor::metrics`, only effective with `metrics` feature. Default is `true`.
+    ///
+    /// Disabling drops collected samples, so statistics start over once enabled again.
+    pub fn metrics_enabled(&self) -> bool {
+        self.settings.lock().metrics
+    }
+
+    pub fn set_metrics_enabled(&self, enabled: bool) {
+        self.update(ConfigChange::Metrics(enabled));
+    }
+
+    /// Warn once runtime thread is blocked in one event loop iteration longer than this. Default is [`None`], disabled.
+    ///
+    /// Watchdog thread emits [`stalled`](crate::executor::stalled) while runtime thread is still blocked,
+    /// once per blocking iteration. Ignored on wasm.
+    pub fn stall_threshold(&self) -> Option<Duration> {
+        self.settings.lock().stall_threshold
+    }
+
+    pub fn set_stall_threshold(&self, threshold: Option<Duration>) {
+        self.update(ConfigChange::StallThreshold(threshold));
+    }
+
+    /// Check if redraws of occluded windows are withheld
+    pub(crate) fn pauses_occluded(&self) -> bool {
+        let settings = *self.settings.lock();
+
+        settings.pause_occluded || settings.power_profile == PowerProfile::PowerSaving
+    }
+
+    /// Timer slack applied to new timers, including slack of power profile
+    pub(crate) fn effective_timer_slack(&self, focused: bool) -> Duration {
+        let settings = *self.settings.lock();
+
+        settings
+            .timer_slack
+            .max(settings.power_profile.timer_slack(focused))
+    }
+
+    /// Check if event loop should keep polling
+    pub(crate) fn continuous(&self, has_windows: bool) -> bool {
+        let settings = *self.settings.lock();
+
+        settings.redraw_policy == RedrawPolicy::Continuous
+            && settings.power_profile != PowerProfile::PowerSaving
+            && (has_windows || !settings.background)
+    }
+
+    /// Check if runtime should exit after last window is closed
+    pub(crate) fn exit_on_close(&self) -> bool {
+        let settings = *self.settings.lock();
+
+        settings.exit_on_last_window_close && !settings.background
+    }
+
+    /// Apply change before runtime starts, without notifying it
+    pub(crate) fn preset(&mut self, change: ConfigChange) {
+        self.settings.get_mut().apply(change);
+    }
+
+    fn update(&self, change: ConfigChange) {
+        if !self.settings.lock().apply(change) {
+            return;
+        }
+
+        // Standalone configuration is not started yet, or belongs to a runtime which exited
+        let Ok(handle) = try_executor_handle() else {
+            return;
+        };
+        if !ptr::eq(handle.config(), self) {
+            return;
+        }
+
+        if let Ok(task) = handle.try_spawn(async move {
+            // Let runtime subsystems adjust before listeners see the change
+            try_executor_handle()?.apply_config(&change);
+            emit!(changed(), &change);
+
+            Ok::<_, RuntimeError>(())
+        }) {
+            task.detach();
+        }
+    }
+}
+
+impl Settings {
+    /// Apply change, returns `true` if value is changed
+    fn apply(&mut self, change: ConfigChange) -> bool {
+        let settings = self;
+
+        match change {
+            ConfigChange::RedrawPolicy(policy) => {
+                mem::replace(&mut settings.redraw_policy, policy) != policy
+            }
+
+            ConfigChange::PowerProfile(profile) => {
+                mem::replace(&mut settings.power_profile, profile) != profile
+            }
+
+            ConfigChange::ExitOnLastWindowClose(exit) => {
+                mem::replace(&mut settings.exit_on_last_window_close, exit) != exit
+            }
+
+            ConfigChange::Background(background) => {
+                mem::replace(&mut settings.background, background) != background
+            }
+
+            ConfigChange::PollBudget(budget) => {
+                mem::replace(&mut settings.poll_budget, budget) != budget
+            }
+
+            ConfigChange::TimerSlack(slack) => {
+                mem::replace(&mut settings.timer_slack, slack) != slack
+            }
+
+            ConfigChange::PauseOccluded(pause) => {
+                mem::replace(&mut settings.pause_occluded, pause) != pause
+            }
+
+            ConfigChange::DispatchOrder(order) => {
+                mem::replace(&mut settings.dispatch_order, order) != order
+            }
+
+            ConfigChange::TeardownDeadline(deadline) => {
+                mem::replace(&mut settings.teardown_deadline, deadline) != deadline
+            }
+
+            ConfigChange::ReportLeakedTasks(report) => {
+                mem::replace(&mut settings.report_leaked_tasks, report) != report
+            }
+
+            ConfigChange::Metrics(enabled) => {
+                mem::replace(&mut settings.metrics, enabled) != enabled
+            }
+
+            ConfigChange::StallThreshold(threshold) => {
+                mem::replace(&mut settings.stall_threshold, threshold) != threshold
+            }
+        }
+    }
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RuntimeConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuntimeConfig")
+            .field("settings", &self.settings)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Native dialogs using rfd, without blocking event loop
+//!
+//! Dialogs are modal to `parent` window if given. Async dialogs of rfd are awaited directly, so no thread is used on wasm.
+//! Other blocking dialog APIs can be offloaded with `spawn_blocking` on native platforms.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+use rfd::{AsyncFileDialog, AsyncMessageDialog};
+use winit::window::Window;
+
+pub use rfd::{MessageButtons, MessageDialogResult, MessageLevel};
+
+#[cfg(target_arch = "wasm32")]
+pub use rfd::FileHandle;
+
+/// Create file dialog attached to parent window, for further configuration
+pub fn file_dialog(parent: Option<&Window>) -> AsyncFileDialog {
+    let dialog = AsyncFileDialog::new();
+
+    match parent {
+        Some(parent) => dialog.set_parent(parent),
+        None => dialog,
+    }
+}
+
+/// Show open file dialog
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn pick_file(parent: Option<&Window>) -> Option<PathBuf> {
+    file_dialog(parent)
+        .pick_file()
+        .await
+        .map(|handle| handle.path().to_path_buf())
+}
+
+/// Show save file dialog
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn save_file(parent: Option<&Window>) -> Option<PathBuf> {
+    file_dialog(parent)
+        .save_file()
+        .await
+        .map(|handle| handle.path().to_path_buf())
+}
+
+/// Show open file dialog. Files have no path on web, read picked file using returned handle.
+#[cfg(target_arch = "wasm32")]
+pub async fn pick_file(parent: Option<&Window>) -> Option<FileHandle> {
+    file_dialog(parent).pick_file().await
+}
+
+/// Get handle for saving file. Browser prompts where to save once [`FileHandle::write`] is called.
+#[cfg(target_arch = "wasm32")]
+pub async fn save_file(parent: Option<&Window>) -> Option<FileHandle> {
+    file_dialog(parent).save_file().await
+}
+
+/// Show message dialog and wait for user response
+pub async fn message(
+    parent: Option<&Window>,
+    level: MessageLevel,
+    buttons: MessageButtons,
+    title: impl Into<String>,
+    description: impl Into<String>,
+) -> MessageDialogResult {
+    let dialog = AsyncMessageDialog::new()
+        .set_level(level)
+        .set_buttons(buttons)
+        .set_title(title)
+        .set_description(description);
+
+    match parent {
+        Some(parent) => dialog.set_parent(parent),
+        None => dialog,
+    }
+    .show()
+    .await
+}
@@ -0,0 +1,186 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! egui integration using egui-winit
+//!
+//! Runtime feeds window events into egui and runs frames on [`WindowEvent::RedrawRequested`].
+//! Rendering is left to the application, like using `egui-wgpu` with [`gfx`](crate::gfx) module.
+//!
+//! ```ignore
+//! egui_app(window, |ctx| {
+//!     egui::CentralPanel::default().show(ctx, |ui| ui.label("Hello"));
+//! }, |frame| renderer.render(frame)).await
+//! ```
+
+use std::{
+    fmt::{self, Debug},
+    sync::{Arc, Weak},
+};
+
+use egui_winit::{
+    egui::{ClippedPrimitive, Context, RequestRepaintInfo, TexturesDelta, ViewportId},
+    State,
+};
+use futures_lite::future;
+use instant::Duration;
+use parking_lot::Mutex;
+use winit::{event::WindowEvent, window::Window};
+
+use crate::{spawn_ui_task, timer::wait, window};
+
+pub use egui_winit::egui;
+
+/// Output of an egui frame, to be rendered by application
+#[derive(Debug)]
+pub struct EguiFrame {
+    pub primitives: Vec<ClippedPrimitive>,
+    pub textures_delta: TexturesDelta,
+    pub pixels_per_point: f32,
+}
+
+/// egui platform state of a window
+pub struct EguiRuntime {
+    window: Arc<Window>,
+    context: Context,
+    state: Mutex<State>,
+}
+
+impl EguiRuntime {
+    pub fn new(window: Arc<Window>) -> Self {
+        let context = Context::default();
+        context.set_request_repaint_callback({
+            let window = Arc::downgrade(&window);
+
+            move |info| request_repaint(&window, info)
+        });
+
+        let state = State::new(
+            context.clone(),
+            ViewportId::ROOT,
+            &window,
+            Some(window.scale_factor() as f32),
+            window.theme(),
+            None,
+        );
+
+        Self {
+            window,
+            context,
+            state: Mutex::new(state),
+        }
+    }
+
+    pub fn window(&self) -> &Arc<Window> {
+        &self.window
+    }
+
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Feed window events into egui and run `ui` every time window is redrawn
+    ///
+    /// Frame output is passed to `render`. Redraw is requested whenever egui needs repaint.
+    pub async fn run(&self, mut ui: impl FnMut(&Context), mut render: impl FnMut(EguiFrame)) -> ! {
+        let id = self.window.id();
+
+        let input = window().on(|(window_id, event), _| {
+            if window_id != id {
+                return;
+            }
+
+            if self
+                .state
+                .lock()
+                .on_window_event(&self.window, event)
+                .repaint
+            {
+                self.window.request_redraw();
+            }
+        });
+
+        let frames = async {
+            self.window.request_redraw();
+
+            loop {
+                window()
+                    .once(|(window_id, event), _| {
+                        matches!(event, WindowEvent::RedrawRequested if window_id == id)
+                            .then_some(())
+                    })
+                    .await;
+
+                render(self.frame(&mut ui));
+            }
+        };
+
+        future::or(
+            async {
+                input.await;
+                unreachable!()
+            },
+            frames,
+        )
+        .await
+    }
+
+    /// Run egui frame
+    fn frame(&self, ui: impl FnMut(&Context)) -> EguiFrame {
+        let input = self.state.lock().take_egui_input(&self.window);
+        let output = self.context.run(input, ui);
+
+        self.state
+            .lock()
+            .handle_platform_output(&self.window, output.platform_output);
+
+        EguiFrame {
+            primitives: self
+                .context
+                .tessellate(output.shapes, output.pixels_per_point),
+            textures_delta: output.textures_delta,
+            pixels_per_point: output.pixels_per_point,
+        }
+    }
+}
+
+impl Debug for EguiRuntime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EguiRuntime")
+            .field("window", &self.window)
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Request redraw of the window after requested delay, called from any thread
+fn request_repaint(window: &Weak<Window>, info: RequestRepaintInfo) {
+    if info.delay == Duration::MAX {
+        return;
+    }
+
+    let window = window.clone();
+    spawn_ui_task(async move {
+        if !info.delay.is_zero() {
+            wait(info.delay).await;
+        }
+
+        if let Some(window) = window.upgrade() {
+            window.request_redraw();
+        }
+    })
+    .detach();
+}
+
+/// Run egui application on given window
+///
+/// See [`EguiRuntime::run`]
+pub async fn egui_app(
+    window: Arc<Window>,
+    ui: impl FnMut(&Context),
+    render: impl FnMut(EguiFrame),
+) -> ! {
+    EguiRuntime::new(window).run(ui, render).await
+}
@@ -0,0 +1,295 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Debug},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+use futures_lite::{future, Stream};
+use higher_kinded_types::ForLifetime;
+use parking_lot::Mutex;
+
+use crate::task::Task;
+
+use super::{remote::spawn_close_on_exit, EventSource};
+
+impl<T: ForLifetime + 'static> EventSource<T> {
+    /// Deliver events to any number of subscribers, each reading at its own pace
+    ///
+    /// Events are converted into owned values with `map` and kept in a ring buffer of `capacity` events, shared by every [`Subscriber`].
+    /// Subscribers falling behind more than `capacity` events skip oldest ones, counted in [`Subscriber::missed`].
+    /// Events are not converted while there are no subscribers. Can be called on any thread while runtime is running.
+    /// ```ignore
+    /// let keys = window().broadcast(64, |(_, event)| match event {
+    ///     WindowEvent::KeyboardInput { event, .. } => Some(event.clone()),
+    ///     _ => None,
+    /// });
+    ///
+    /// for plugin in &mut plugins {
+    ///     plugin.start(keys.subscribe());
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero
+    pub fn broadcast<F, R>(&'static self, capacity: usize, mut map: F) -> Broadcast<R>
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send + 'static,
+        R: Clone + Send + 'static,
+    {
+        assert!(capacity > 0, "Buffer capacity must be non zero");
+
+        let ring = Arc::new(Mutex::new(Ring::new(capacity)));
+
+        let task = spawn_close_on_exit(
+            self,
+            {
+                let ring = ring.clone();
+
+                move |event, _| {
+                    if ring.lock().subscribers == 0 {
+                        return;
+                    }
+
+                    if let Some(event) = map(event) {
+                        ring.lock().push(event);
+                    }
+                }
+            },
+            {
+                let ring = ring.clone();
+                move || ring.lock().close()
+            },
+        );
+
+        Broadcast { ring, _task: task }
+    }
+}
+
+struct Ring<R> {
+    events: VecDeque<R>,
+    capacity: usize,
+
+    /// Sequence number of first event in buffer
+    start: u64,
+    closed: bool,
+
+    subscribers: usize,
+    next_id: u64,
+    wakers: HashMap<u64, Waker>,
+}
+
+impl<R> Ring<R> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+            start: 0,
+            closed: false,
+            subscribers: 0,
+            next_id: 0,
+            wakers: HashMap::new(),
+        }
+    }
+
+    /// Sequence number of next event
+    fn end(&self) -> u64 {
+        self.start + self.events.len() as u64
+    }
+
+    fn push(&mut self, event: R) {
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+            self.start += 1;
+        }
+        self.events.push_back(event);
+
+        self.wake();
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+        self.wake();
+    }
+
+    fn wake(&mut self) {
+        for (_, waker) in self.wakers.drain() {
+            waker.wake();
+        }
+    }
+
+    fn subscribe(&mut self) -> u64 {
+        self.subscribers += 1;
+
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+}
+
+/// Broadcast created with [`EventSource::broadcast`], which can be moved to other threads
+///
+/// Stops listening once dropped, subscribers can still take buffered events.
+#[must_use = "broadcast stops listening once dropped"]
+pub struct Broadcast<R> {
+    ring: Arc<Mutex<Ring<R>>>,
+    _task: Task<()>,
+}
+
+impl<R: Clone> Broadcast<R> {
+    /// Create subscriber receiving events emitted from now on
+    pub fn subscribe(&self) -> Subscriber<R> {
+        let mut ring = self.ring.lock();
+        let cursor = ring.end();
+
+        Subscriber {
+            ring: self.ring.clone(),
+            id: ring.subscribe(),
+            cursor,
+            missed: 0,
+        }
+    }
+}
+
+impl<R> Broadcast<R> {
+    /// Number of live subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.ring.lock().subscribers
+    }
+
+    /// Check if listener stopped
+    pub fn is_closed(&self) -> bool {
+        self.ring.lock().closed
+    }
+}
+
+impl<R> Debug for Broadcast<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Broadcast")
+            .field("subscribers", &self.subscriber_count())
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Subscriber of a [`Broadcast`] with its own read position
+///
+/// Cloned subscriber starts from same position.
+#[must_use = "streams do nothing unless polled"]
+pub struct Subscriber<R> {
+    ring: Arc<Mutex<Ring<R>>>,
+    id: u64,
+    cursor: u64,
+    missed: u64,
+}
+
+impl<R: Clone> Subscriber<R> {
+    /// Wait for next event
+    ///
+    /// Returns [`None`] once every buffered event is taken after listener stopped, like when runtime exited.
+    pub async fn next(&mut self) -> Option<R> {
+        future::poll_fn(|cx| self.poll_event(Some(cx))).await
+    }
+
+    /// Take next event without waiting
+    pub fn try_next(&mut self) -> Option<R> {
+        match self.poll_event(None) {
+            Poll::Ready(event) => event,
+            Poll::Pending => None,
+        }
+    }
+
+    fn poll_event(&mut self, cx: Option<&mut Context<'_>>) -> Poll<Option<R>> {
+        let mut ring = self.ring.lock();
+
+        if self.cursor < ring.start {
+            self.missed += ring.start - self.cursor;
+            self.cursor = ring.start;
+        }
+
+        if self.cursor < ring.end() {
+            let event = ring.events[(self.cursor - ring.start) as usize].clone();
+            self.cursor += 1;
+
+            return Poll::Ready(Some(event));
+        }
+
+        if ring.closed {
+            return Poll::Ready(None);
+        }
+
+        if let Some(cx) = cx {
+            ring.wakers.insert(self.id, cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl<R> Subscriber<R> {
+    /// Number of buffered events not taken yet
+    pub fn len(&self) -> usize {
+        let ring = self.ring.lock();
+
+        (ring.end() - self.cursor.max(ring.start)) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of events skipped so far by falling behind
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+
+    /// Check if listener stopped
+    pub fn is_closed(&self) -> bool {
+        self.ring.lock().closed
+    }
+}
+
+impl<R: Clone> Stream for Subscriber<R> {
+    type Item = R;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        self.get_mut().poll_event(Some(cx))
+    }
+}
+
+impl<R> Clone for Subscriber<R> {
+    fn clone(&self) -> Self {
+        let id = self.ring.lock().subscribe();
+
+        Self {
+            ring: self.ring.clone(),
+            id,
+            cursor: self.cursor,
+            missed: self.missed,
+        }
+    }
+}
+
+impl<R> Drop for Subscriber<R> {
+    fn drop(&mut self) {
+        let mut ring = self.ring.lock();
+        ring.subscribers -= 1;
+        ring.wakers.remove(&self.id);
+    }
+}
+
+impl<R> Debug for Subscriber<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscriber")
+            .field("len", &self.len())
+            .field("missed", &self.missed)
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
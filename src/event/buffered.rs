@@ -0,0 +1,224 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+use futures_lite::{future, Stream};
+use higher_kinded_types::ForLifetime;
+use parking_lot::Mutex;
+
+use super::EventSource;
+
+/// Behaviour of [`Buffered`] listener once its queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Overflow {
+    /// Drop oldest queued event
+    #[default]
+    DropOldest,
+
+    /// Replace newest queued event with new one, for state-like events such as cursor position
+    Coalesce,
+
+    /// Panic on the task consuming events, so emitting thread is not affected
+    Panic,
+}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Listen events into a queue, so events emitted while consumer task is busy are not missed
+    ///
+    /// Events are converted into owned values with `map` and queued up to `capacity`, handled by `overflow` once full.
+    /// Listening starts once returned [`Buffered`] is first polled, like [`EventSource::on`].
+    /// ```ignore
+    /// let mut keys = window().on_buffered(64, Overflow::DropOldest, |(_, event)| match event {
+    ///     WindowEvent::KeyboardInput { event, .. } => Some(event.clone()),
+    ///     _ => None,
+    /// });
+    ///
+    /// loop {
+    ///     handle_key(keys.next().await).await;
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero
+    pub fn on_buffered<'a, F, R>(
+        &'a self,
+        capacity: usize,
+        overflow: Overflow,
+        mut map: F,
+    ) -> Buffered<'a, R>
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send + 'a,
+        R: Send + 'a,
+    {
+        assert!(capacity > 0, "Buffer capacity must be non zero");
+
+        let queue = Arc::new(Mutex::new(Queue::new()));
+
+        let listener = Box::pin(self.on({
+            let queue = queue.clone();
+
+            move |event, _| {
+                if let Some(event) = map(event) {
+                    queue.lock().push(event, capacity, overflow);
+                }
+            }
+        }));
+
+        Buffered { listener, queue }
+    }
+}
+
+pub(super) struct Queue<R> {
+    pub events: VecDeque<R>,
+    pub waker: Option<Waker>,
+    pub overflowed: usize,
+
+    /// Listener stopped, so no more events are queued
+    pub closed: bool,
+
+    /// Capacity of the queue overflowed with [`Overflow::Panic`], for panicking on consumer side
+    pub overflow_panic: Option<usize>,
+}
+
+impl<R> Queue<R> {
+    pub const fn new() -> Self {
+        Self {
+            events: VecDeque::new(),
+            waker: None,
+            overflowed: 0,
+            closed: false,
+            overflow_panic: None,
+        }
+    }
+
+    pub fn push(&mut self, event: R, capacity: usize, overflow: Overflow) {
+        if self.events.len() >= capacity {
+            self.overflowed += 1;
+
+            match overflow {
+                Overflow::DropOldest => {
+                    self.events.pop_front();
+                }
+
+                Overflow::Coalesce => {
+                    self.events.pop_back();
+                }
+
+                Overflow::Panic => panic!("Event buffer overflowed, capacity: {capacity}"),
+            }
+        }
+
+        self.events.push_back(event);
+
+        self.wake();
+    }
+
+    /// Like [`Queue::push`], but records [`Overflow::Panic`] for consumer instead of panicking in listener.
+    /// Returns `false` once overflowed, so listener should stop.
+    pub fn push_or_defer(&mut self, event: R, capacity: usize, overflow: Overflow) -> bool {
+        if overflow == Overflow::Panic && self.events.len() >= capacity {
+            self.overflowed += 1;
+            self.overflow_panic = Some(capacity);
+            self.close();
+            return false;
+        }
+
+        self.push(event, capacity, overflow);
+        true
+    }
+
+    pub fn close(&mut self) {
+        self.closed = true;
+        self.wake();
+    }
+
+    /// Register waker of consumer, skipping clone if it is same
+    pub fn register(&mut self, waker: &Waker) {
+        if !self.waker.as_ref().is_some_and(|old| old.will_wake(waker)) {
+            self.waker = Some(waker.clone());
+        }
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Buffered listener created with [`EventSource::on_buffered`]
+///
+/// Stops listening once dropped.
+#[must_use = "streams do nothing unless polled"]
+pub struct Buffered<'a, R> {
+    listener: Pin<Box<dyn Future<Output = ()> + Send + 'a>>,
+    queue: Arc<Mutex<Queue<R>>>,
+}
+
+impl<R> Buffered<'_, R> {
+    /// Wait for next queued event
+    pub async fn next(&mut self) -> R {
+        future::poll_fn(|cx| self.poll_event(cx)).await
+    }
+
+    /// Take next queued event without waiting
+    pub fn try_next(&mut self) -> Option<R> {
+        self.queue.lock().events.pop_front()
+    }
+
+    /// Number of queued events
+    pub fn len(&self) -> usize {
+        self.queue.lock().events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.lock().events.is_empty()
+    }
+
+    /// Number of events dropped or coalesced by overflow so far
+    pub fn overflowed(&self) -> usize {
+        self.queue.lock().overflowed
+    }
+
+    fn poll_event(&mut self, cx: &mut Context<'_>) -> Poll<R> {
+        // Keeps listener registered, and continues its panic
+        let _ = self.listener.as_mut().poll(cx);
+
+        let mut queue = self.queue.lock();
+        if let Some(event) = queue.events.pop_front() {
+            return Poll::Ready(event);
+        }
+
+        queue.register(cx.waker());
+        Poll::Pending
+    }
+}
+
+impl<R> Stream for Buffered<'_, R> {
+    type Item = R;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        self.get_mut().poll_event(cx).map(Some)
+    }
+}
+
+impl<R> Debug for Buffered<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Buffered")
+            .field("len", &self.len())
+            .field("overflowed", &self.overflowed())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,291 @@
+/*
+ * Created on Thu Sep 07 2023
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    any::Any,
+    future::Future,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use higher_kinded_types::ForLifetime;
+use sync_wrapper::SyncWrapper;
+use unique::Unique;
+
+use super::{
+    sealed::{LivenessToken, Sealed},
+    types::Node,
+    EventMeta, EventSource,
+};
+
+pin_project_lite::pin_project!(
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    /// Future created with [`EventSource::on`]
+    pub struct EventFnFuture<'a, F, T: ForLifetime> {
+        source: &'a EventSource<T>,
+
+        #[pin]
+        listener: Sealed<F>,
+
+        #[pin]
+        node: Node<T>,
+    }
+
+    impl<F, T: ForLifetime> PinnedDrop for EventFnFuture<'_, F, T> {
+        fn drop(this: Pin<&mut Self>) {
+            let project = this.project();
+            let node = match project.node.initialized_mut() {
+                Some(initialized) => initialized,
+                None => return,
+            };
+
+            let _ = node.reset(&mut project.source.list.lock());
+            project.source.unsubscribed();
+        }
+    }
+);
+
+impl<'a, T: ForLifetime, F> EventFnFuture<'a, F, T> {
+    pub(super) const fn new(source: &'a EventSource<T>, listener: F) -> Self {
+        Self {
+            source,
+            listener: Sealed::new(listener),
+            node: pin_list::Node::new(),
+        }
+    }
+}
+
+impl<'a, T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send> Future
+    for EventFnFuture<'a, F, T>
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_listener(cx)
+    }
+}
+
+impl<'a, T: ForLifetime, F> EventFnFuture<'a, F, T> {
+    /// Unregister listener and access it, so state it holds can be taken once finished
+    pub(super) fn unlink(self: Pin<&mut Self>) -> &mut F {
+        let project = self.project();
+
+        if let Some(node) = project.node.initialized_mut() {
+            let _ = node.reset(&mut project.source.list.lock());
+            project.source.unsubscribed();
+        }
+
+        project.listener.inner_mut()
+    }
+
+    pub(super) fn poll_listener(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()>
+    where
+        F: Listener<T> + Send,
+    {
+        let mut this = self.project();
+
+        // Polled inside of listener of same source, retry once emitting is finished
+        if this.source.is_emitting() {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        let mut list = this.source.list.lock();
+        let mut subscribed = None;
+        let node = {
+            let initialized = match this.node.as_mut().initialized_mut() {
+                Some(initialized) => initialized,
+                None => {
+                    subscribed = Some(this.source.add_listener());
+
+                    let token = this.listener.as_mut().token();
+                    list.push_back(
+                        this.node,
+                        ListenerItem::new(
+                            Unique::new(this.listener.as_mut().get_ptr_mut().as_ptr() as _)
+                                .unwrap(),
+                            token,
+                        ),
+                        (),
+                    )
+                }
+            };
+
+            initialized.protected_mut(&mut list).unwrap()
+        };
+
+        #[cfg(debug_assertions)]
+        node.validate(this.listener.get_ptr_mut().cast());
+
+        // Continue panic occurred while running listener on the task owning it
+        if let Some(payload) = node.panic.take() {
+            drop(list);
+            panic::resume_unwind(payload);
+        }
+
+        if node.done {
+            return Poll::Ready(());
+        }
+
+        node.update_waker(cx.waker());
+        drop(list);
+
+        if let Some(count) = subscribed {
+            this.source.subscribed(count);
+        }
+
+        Poll::Pending
+    }
+}
+
+/// Listener called by emitter
+pub(super) trait Listener<T: ForLifetime> {
+    fn call(&mut self, event: T::Of<'_>, flow: &mut ControlFlow);
+}
+
+impl<T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow)> Listener<T> for F {
+    fn call(&mut self, event: T::Of<'_>, flow: &mut ControlFlow) {
+        self(event, flow)
+    }
+}
+
+type DynClosure<'closure, T> = dyn Listener<T> + Send + 'closure;
+
+#[derive(Debug)]
+pub struct ListenerItem<T: ForLifetime> {
+    done: bool,
+    waker: Option<Waker>,
+    panicked: bool,
+    panic: Option<Box<dyn Any + Send>>,
+    closure_ptr: SyncWrapper<Unique<DynClosure<'static, T>>>,
+
+    /// Checks closure is not dropped while node is linked, on debug builds
+    closure_token: LivenessToken,
+}
+
+impl<T: ForLifetime> ListenerItem<T> {
+    fn new(closure: Unique<DynClosure<T>>, closure_token: LivenessToken) -> Self {
+        Self {
+            done: false,
+            waker: None,
+            panicked: false,
+            panic: None,
+            closure_token,
+
+            // SAFETY: Only lifetime is erased. Closure is owned by EventFnFuture which unlinks this node on drop,
+            // before the closure itself is dropped. So pointer is valid while the node is in the list, see ListenerItem::poll.
+            closure_ptr: SyncWrapper::new(unsafe {
+                mem::transmute::<Unique<_>, Unique<_>>(closure)
+            }),
+        }
+    }
+
+    /// Abort if node does not point to closure of the future polling it, or the closure is dropped
+    #[cfg(debug_assertions)]
+    fn validate(&mut self, closure: std::ptr::NonNull<()>) {
+        self.closure_token
+            .check("Listener node is polled after its closure is dropped");
+
+        if self.closure_ptr.get_mut().as_ptr() as *mut () != closure.as_ptr() {
+            super::sealed::abort("Listener node points to closure of other future");
+        }
+    }
+
+    fn update_waker(&mut self, waker: &Waker) {
+        match self.waker {
+            Some(ref stored) if stored.will_wake(waker) => (),
+
+            _ => {
+                self.waker = Some(waker.clone());
+            }
+        }
+    }
+
+    fn set_done(&mut self) {
+        self.done = true;
+
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// # Safety
+    /// Calling this method is only safe if pointer to closure is valid
+    pub unsafe fn poll(&mut self, event: T::Of<'_>, meta: EventMeta) -> bool {
+        // Panicked listener may be in inconsistent state, never call it again
+        if self.panicked {
+            return true;
+        }
+
+        let mut flow = ControlFlow {
+            done: self.done,
+            propagation: true,
+            meta,
+        };
+
+        self.closure_token
+            .check("Listener node outlived its closure and is emitted");
+        let closure = self.closure_ptr.get_mut().as_mut();
+
+        // Isolate panic from emitter and other listeners.
+        // Node is left untouched until closure returns, so list stays consistent after the panic.
+        if let Err(payload) =
+            panic::catch_unwind(AssertUnwindSafe(|| closure.call(event, &mut flow)))
+        {
+            self.panicked = true;
+            self.panic = Some(payload);
+
+            if !self.done {
+                self.set_done();
+            }
+
+            return true;
+        }
+
+        if flow.done && !self.done {
+            self.set_done();
+        }
+
+        flow.propagation
+    }
+}
+
+#[derive(Debug)]
+/// Control current listener's behaviour
+pub struct ControlFlow {
+    done: bool,
+    propagation: bool,
+    meta: EventMeta,
+}
+
+impl ControlFlow {
+    /// Stop propagation of the current event
+    pub fn stop_propagation(&mut self) {
+        if self.propagation {
+            self.propagation = false;
+        }
+    }
+
+    /// Metadata of the current event
+    pub const fn meta(&self) -> EventMeta {
+        self.meta
+    }
+
+    /// Check if listener is finished already
+    pub const fn done(&self) -> bool {
+        self.done
+    }
+
+    /// Mark listener as finished
+    pub fn set_done(&mut self) {
+        if !self.done {
+            self.done = true;
+        }
+    }
+}
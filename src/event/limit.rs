@@ -0,0 +1,160 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    fmt::{self, Debug},
+    pin::pin,
+};
+
+use higher_kinded_types::ForLifetime;
+use instant::{Duration, Instant};
+use parking_lot::Mutex;
+
+use crate::timer;
+
+use super::EventSource;
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Rate limit events, yielding latest mapped event once no event is mapped for given duration
+    ///
+    /// ```ignore
+    /// let mut resized = window().debounce(Duration::from_millis(200), |(_, event)| match event {
+    ///     WindowEvent::Resized(size) => Some(*size),
+    ///     _ => None,
+    /// });
+    ///
+    /// loop {
+    ///     relayout(resized.next().await);
+    /// }
+    /// ```
+    pub fn debounce<F, R>(&self, duration: Duration, map: F) -> Debounce<'_, T, F>
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send,
+        R: Send,
+    {
+        Debounce {
+            source: self,
+            duration,
+            map,
+        }
+    }
+
+    /// Rate limit events, yielding latest mapped event at most once per given duration
+    ///
+    /// First event after a quiet period is yielded immediately.
+    pub fn throttle<F, R>(&self, duration: Duration, map: F) -> Throttle<'_, T, F>
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send,
+        R: Send,
+    {
+        Throttle {
+            source: self,
+            duration,
+            map,
+            last: None,
+        }
+    }
+}
+
+/// Debounced event source created with [`EventSource::debounce`]
+///
+/// Events are only captured while [`Debounce::next`] is awaited.
+pub struct Debounce<'a, T: ForLifetime, F> {
+    source: &'a EventSource<T>,
+    duration: Duration,
+    map: F,
+}
+
+impl<T: ForLifetime, F> Debounce<'_, T, F> {
+    /// Wait for next debounced event
+    pub async fn next<R>(&mut self) -> R
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send,
+        R: Send,
+    {
+        let latest = Mutex::new(None);
+
+        // Listener stays registered after done, until it is dropped
+        let mut listener = pin!(self.source.on(|event, flow| {
+            if let Some(value) = (self.map)(event) {
+                *latest.lock() = Some((value, timer::now()));
+                flow.set_done();
+            }
+        }));
+        listener.as_mut().await;
+
+        loop {
+            let deadline = latest.lock().as_ref().unwrap().1 + self.duration;
+
+            if timer::now() >= deadline {
+                break latest.lock().take().unwrap().0;
+            }
+
+            timer::wait_until(deadline).await;
+        }
+    }
+}
+
+impl<T: ForLifetime, F> Debug for Debounce<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Debounce")
+            .field("source", &self.source)
+            .field("duration", &self.duration)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Throttled event source created with [`EventSource::throttle`]
+///
+/// Events are only captured while [`Throttle::next`] is awaited.
+pub struct Throttle<'a, T: ForLifetime, F> {
+    source: &'a EventSource<T>,
+    duration: Duration,
+    map: F,
+    last: Option<Instant>,
+}
+
+impl<T: ForLifetime, F> Throttle<'_, T, F> {
+    /// Wait for next throttled event
+    pub async fn next<R>(&mut self) -> R
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send,
+        R: Send,
+    {
+        let latest = Mutex::new(None);
+
+        let value = {
+            let map = &mut self.map;
+            let mut listener = pin!(self.source.on(|event, flow| {
+                if let Some(value) = map(event) {
+                    *latest.lock() = Some(value);
+                    flow.set_done();
+                }
+            }));
+            listener.as_mut().await;
+
+            // Keep updating latest event until next window starts
+            if let Some(last) = self.last {
+                timer::wait_until(last + self.duration).await;
+            }
+
+            latest.lock().take().unwrap()
+        };
+
+        self.last = Some(timer::now());
+        value
+    }
+}
+
+impl<T: ForLifetime, F> Debug for Throttle<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Throttle")
+            .field("source", &self.source)
+            .field("duration", &self.duration)
+            .field("last", &self.last)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    marker::PhantomData,
+    pin::pin,
+    thread::{self, ThreadId},
+};
+
+use futures_lite::future;
+
+use higher_kinded_types::ForLifetime;
+
+use crate::executor::executor_handle;
+
+use super::{
+    future::{EventFnFuture, Listener},
+    once::OnceListener,
+    ControlFlow, EventSource,
+};
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Listen events with non [`Send`] listener, on runtime thread
+    ///
+    /// Like [`ExecutorHandle::spawn_local`], panics if it is not called on runtime thread.
+    /// Listener panics if the event is emitted on other threads.
+    ///
+    /// [`ExecutorHandle::spawn_local`]: crate::executor::handle::ExecutorHandle::spawn_local
+    pub async fn on_local<F>(&self, listener: F)
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow),
+    {
+        executor_handle().assert_runtime_thread("on_local");
+
+        // Held across await, keeping returned future on current thread
+        let _local = PhantomData::<*const ()>;
+
+        let mut future = pin!(EventFnFuture::new(self, LocalListener::new(listener)));
+        future::poll_fn(|cx| future.as_mut().poll_listener(cx)).await;
+    }
+
+    /// Listen event until non [`Send`] listener returns [`Option::Some`], on runtime thread
+    ///
+    /// See [`EventSource::on_local`] and [`EventSource::once`]
+    ///
+    /// Output is stored inline like [`EventSource::once`], so it does not need to be [`Send`].
+    pub async fn once_local<F, R>(&self, listener: F) -> Option<R>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) -> Option<R>,
+    {
+        executor_handle().assert_runtime_thread("once_local");
+        let _local = PhantomData::<*const ()>;
+
+        let mut future = pin!(EventFnFuture::new(
+            self,
+            LocalListener::new(OnceListener::new(listener))
+        ));
+        future::poll_fn(|cx| future.as_mut().poll_listener(cx)).await;
+
+        future.as_mut().unlink().get_mut().take()
+    }
+}
+
+/// Listener only accessible on the thread created
+struct LocalListener<F> {
+    inner: F,
+    thread_id: ThreadId,
+}
+
+// SAFETY: Listener is only accessed on the thread it is created, checked on every access.
+// Owner future is not Send, so listener is dropped on the same thread too.
+unsafe impl<F> Send for LocalListener<F> {}
+
+impl<T: ForLifetime, L: Listener<T>> Listener<T> for LocalListener<L> {
+    fn call(&mut self, event: T::Of<'_>, flow: &mut ControlFlow) {
+        self.get_mut().call(event, flow)
+    }
+}
+
+impl<F> LocalListener<F> {
+    fn new(inner: F) -> Self {
+        Self {
+            inner,
+            thread_id: thread::current().id(),
+        }
+    }
+
+    fn get_mut(&mut self) -> &mut F {
+        if thread::current().id() != self.thread_id {
+            panic!("Local listener is called outside of event loop thread");
+        }
+
+        &mut self.inner
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use instant::Instant;
+
+use crate::executor::try_executor_handle;
+
+/// Next sequence number, shared by every source
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Metadata of event being emitted on current thread
+    static CURRENT: Cell<Option<EventMeta>> = const { Cell::new(None) };
+}
+
+/// Metadata of emitted event, for re-establishing global ordering of events from multiple sources
+///
+/// Every emitted event gets a sequence number, increasing monotonically across every source and thread.
+/// Events emitted while another event is emitted on same thread share its metadata.
+/// So every source emitted for one winit event, like `window` and [`raw_event`](crate::raw_event()), and custom events emitted from their listeners share a number.
+/// ```ignore
+/// window().on(|(_, event), flow| {
+///     log.push((flow.meta().seq(), format!("{event:?}")));
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventMeta {
+    seq: u64,
+    time: Instant,
+}
+
+impl EventMeta {
+    fn next() -> Self {
+        Self {
+            seq: SEQUENCE.fetch_add(1, Ordering::Relaxed),
+            time: try_executor_handle()
+                .map(|handle| handle.now())
+                .unwrap_or_else(|_| Instant::now()),
+        }
+    }
+
+    /// Sequence number. Event with smaller number is dispatched earlier.
+    pub const fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Time of runtime clock when event is dispatched
+    pub const fn time(&self) -> Instant {
+        self.time
+    }
+}
+
+/// Metadata of event being emitted on current thread, returns [`None`] outside of listeners
+pub fn current_meta() -> Option<EventMeta> {
+    CURRENT.with(Cell::get)
+}
+
+/// Run `f` with metadata of current event, assigning new one if no event is being emitted
+pub(crate) fn with_meta<R>(f: impl FnOnce(EventMeta) -> R) -> R {
+    if let Some(meta) = current_meta() {
+        return f(meta);
+    }
+
+    let meta = EventMeta::next();
+    CURRENT.with(|current| current.set(Some(meta)));
+    let _guard = Reset;
+
+    f(meta)
+}
+
+/// Clears current metadata once outermost event is finished, even when unwinding
+struct Reset;
+
+impl Drop for Reset {
+    fn drop(&mut self) {
+        let _ = CURRENT.try_with(|current| current.set(None));
+    }
+}
@@ -0,0 +1,379 @@
+/*
+ * Created on Thu Aug 10 2023
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Zero cost non buffered async event emitter
+//!
+//! ## Features
+//! 1. Non buffered, immediate event dispatch
+//! 2. Zero cost listener adding, removing
+//! 3. Higher kinded event type
+//! 4. Propagation control
+//! 5. Panicking listeners are isolated from the emitter and other listeners
+//! 6. Sequence numbers for global ordering across sources, see [`EventMeta`]
+//!
+//! ## Custom event sources
+//! Every API here is safe, so other crates can declare their own sources and emit on them.
+//! ```ignore
+//! use winit_runtime::{define_event, emit};
+//!
+//! // Static source using higher kinded type, event can borrow emitter's data
+//! define_event!(pub progress: (&str, f32));
+//!
+//! // Or construct it directly
+//! static SAVED: winit_runtime::EventSource!(&Path) = EventSource::new();
+//!
+//! emit!(progress(), ("loading", 0.5));
+//! progress().on(|(stage, value), _| println!("{stage}: {value}")).await;
+//! ```
+//! Listeners run synchronously while emitting, on the emitting thread.
+//! Listener futures polled inside of a listener of same source are registered once emitting is finished,
+//! so they observe events from next emit, but they must not be dropped there.
+
+#[doc(hidden)]
+pub mod __private {
+    pub use higher_kinded_types::ForLt;
+}
+
+mod broadcast;
+mod buffered;
+mod future;
+mod limit;
+mod local;
+mod meta;
+mod once;
+mod remote;
+mod sealed;
+#[cfg(test)]
+mod tests;
+mod types;
+
+pub use broadcast::{Broadcast, Subscriber};
+pub use buffered::{Buffered, Overflow};
+pub use future::{ControlFlow, EventFnFuture};
+pub use limit::{Debounce, Throttle};
+pub use meta::{current_meta, EventMeta};
+pub use once::OnceFuture;
+pub use remote::Remote;
+
+pub(crate) use meta::with_meta;
+
+use std::{
+    error::Error,
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    task::{Context, Poll},
+};
+
+use futures_lite::future::or;
+use higher_kinded_types::ForLifetime;
+use instant::Duration;
+use parking_lot::Mutex;
+
+use pin_list::{id::Unchecked, CursorMut};
+
+use types::{NodeTypes, PinList};
+
+use crate::timer;
+
+#[macro_export]
+/// Higher kinded type helper for [`struct@EventSource`]
+macro_rules! EventSource {
+    ($($ty: tt)*) => {
+        $crate::event::EventSource<$crate::event::__private::ForLt!($($ty)*)>
+    };
+}
+
+#[macro_export]
+/// Emit event. As methods can't do mutable reborrowing correctly, you should use this macro.
+macro_rules! emit {
+    ($source: expr, $event: expr) => {
+        $source.with_emitter(|mut emitter| while emitter.emit_next($event).is_some() {});
+    };
+}
+
+/// Event source
+pub struct EventSource<T: ForLifetime> {
+    list: Mutex<PinList<T>>,
+    listeners: AtomicUsize,
+    hooked: AtomicBool,
+    emitter: AtomicUsize,
+    subscribe_hook: Mutex<Option<Hook>>,
+    unsubscribe_hook: Mutex<Option<Hook>>,
+}
+
+type Hook = Box<dyn Fn(usize) + Send + Sync>;
+
+impl<T: ForLifetime> Debug for EventSource<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventSource")
+            .field("list", &self.list)
+            .field("listeners", &self.listeners)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Create new [`struct@EventSource`]
+    pub const fn new() -> Self {
+        Self {
+            // SAFETY: There is only one variant of [`Pinlist`]
+            list: Mutex::new(PinList::new(unsafe { Unchecked::new() })),
+            listeners: AtomicUsize::new(0),
+            hooked: AtomicBool::new(false),
+            emitter: AtomicUsize::new(0),
+            subscribe_hook: Mutex::new(None),
+            unsubscribe_hook: Mutex::new(None),
+        }
+    }
+
+    /// Number of registered listeners
+    pub fn listener_count(&self) -> usize {
+        self.listeners.load(Ordering::Acquire)
+    }
+
+    /// Set hook called with new listener count every time listener is registered, replacing previous one
+    ///
+    /// Hook is called on the thread polling listener. It must not register listeners on this source.
+    pub fn on_subscribe(&self, hook: impl Fn(usize) + Send + Sync + 'static) {
+        *self.subscribe_hook.lock() = Some(Box::new(hook));
+        self.hooked.store(true, Ordering::Release);
+    }
+
+    /// Set hook called with new listener count every time listener is unregistered, replacing previous one
+    ///
+    /// Hook is called on the thread dropping listener. It must not register listeners on this source.
+    pub fn on_unsubscribe(&self, hook: impl Fn(usize) + Send + Sync + 'static) {
+        *self.unsubscribe_hook.lock() = Some(Box::new(hook));
+        self.hooked.store(true, Ordering::Release);
+    }
+
+    /// Increase listener count, returns new count. Must be called while holding list lock.
+    pub(super) fn add_listener(&self) -> usize {
+        self.listeners.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    pub(super) fn subscribed(&self, count: usize) {
+        // Skip locking hooks on listener churn, if there was never a hook
+        if !self.hooked.load(Ordering::Acquire) {
+            return;
+        }
+
+        if let Some(ref hook) = *self.subscribe_hook.lock() {
+            hook(count);
+        }
+    }
+
+    pub(super) fn unsubscribed(&self) {
+        let count = self.listeners.fetch_sub(1, Ordering::AcqRel) - 1;
+        if !self.hooked.load(Ordering::Acquire) {
+            return;
+        }
+
+        if let Some(ref hook) = *self.unsubscribe_hook.lock() {
+            hook(count);
+        }
+    }
+
+    /// Create [`EventEmitter`] for this [`struct@EventSource`]
+    ///
+    /// `emit_fn` is not called if there is no listener, which only loads an atomic counter without locking.
+    /// Otherwise listener list is locked while emitting, even for one listener, as listeners can be registered and dropped on other threads.
+    /// Panics if called inside of listener of this source, as event cannot be emitted recursively.
+    pub fn with_emitter(&self, emit_fn: impl FnOnce(EventEmitter<T>)) {
+        if self.listener_count() == 0 {
+            return;
+        }
+
+        if self.is_emitting() {
+            panic!("Cannot emit recursively inside of listener of same EventSource");
+        }
+
+        let mut list = self.list.lock();
+
+        self.emitter.store(thread_token(), Ordering::Relaxed);
+        let _emitting = Emitting(&self.emitter);
+
+        meta::with_meta(|meta| {
+            emit_fn(EventEmitter {
+                cursor: list.cursor_front_mut(),
+                meta,
+            })
+        });
+    }
+
+    /// Check if this source is emitting on current thread, so its list is locked by caller
+    pub(super) fn is_emitting(&self) -> bool {
+        self.emitter.load(Ordering::Relaxed) == thread_token()
+    }
+
+    /// Listen events
+    ///
+    /// It can be called after woken if another event occurred before task continue
+    pub fn on<F>(&self, listener: F) -> EventFnFuture<'_, F, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        EventFnFuture::new(self, listener)
+    }
+
+    /// Listen event until listener returns [`Option::Some`]
+    ///
+    /// Unlike [`EventSource::on`] it will ignore every events once listener is done or returns with [`Option::Some`].
+    /// Listener and its output are stored inline in returned future.
+    pub fn once<F, R>(&self, listener: F) -> OnceFuture<'_, F, T, R>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) -> Option<R> + Send,
+        R: Send,
+    {
+        OnceFuture::new(self, listener)
+    }
+
+    /// Wait until listener maps event into [`Option::Some`]
+    ///
+    /// Returned future is allocated on heap and [`Unpin`], so it can be used with select combinators directly.
+    pub fn wait_map<'a, F, R>(&'a self, mut listener: F) -> WaitMap<'a, R>
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send + 'a,
+        R: Send + 'a,
+    {
+        WaitMap {
+            inner: Box::pin(async move {
+                loop {
+                    if let Some(output) = self.once(|event, _| listener(event)).await {
+                        break output;
+                    }
+                }
+            }),
+        }
+    }
+
+    /// Wait until listener maps event into [`Option::Some`], failing with [`TimedOut`] once `timeout` elapses
+    ///
+    /// Timeout uses runtime timer, so it must be awaited while runtime is running.
+    /// ```ignore
+    /// window()
+    ///     .wait_for(
+    ///         |(id, event)| (id == window_id && matches!(event, WindowEvent::Focused(true))).then_some(()),
+    ///         Some(Duration::from_secs(5)),
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn wait_for<F, R>(
+        &self,
+        mut listener: F,
+        timeout: Option<Duration>,
+    ) -> Result<R, TimedOut>
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send,
+        R: Send,
+    {
+        let wait = async {
+            loop {
+                if let Some(output) = self.once(|event, _| listener(event)).await {
+                    break Ok(output);
+                }
+            }
+        };
+
+        match timeout {
+            Some(timeout) => {
+                or(wait, async {
+                    timer::wait(timeout).await;
+                    Err(TimedOut)
+                })
+                .await
+            }
+
+            None => wait.await,
+        }
+    }
+}
+
+/// Error returned by [`EventSource::wait_for`] once timeout elapses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("timed out waiting for event")
+    }
+}
+
+impl Error for TimedOut {}
+
+/// Unique token of current thread
+fn thread_token() -> usize {
+    thread_local! {
+        static TOKEN: u8 = const { 0 };
+    }
+
+    TOKEN.with(|token| token as *const u8 as usize)
+}
+
+/// Clears emitting thread once emitting is finished
+struct Emitting<'a>(&'a AtomicUsize);
+
+impl Drop for Emitting<'_> {
+    fn drop(&mut self) {
+        self.0.store(0, Ordering::Relaxed);
+    }
+}
+
+impl<T: ForLifetime> Default for EventSource<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Future returned by [`EventSource::wait_map`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitMap<'a, R> {
+    inner: Pin<Box<dyn Future<Output = R> + Send + 'a>>,
+}
+
+impl<R> Debug for WaitMap<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitMap").finish_non_exhaustive()
+    }
+}
+
+impl<R> Future for WaitMap<'_, R> {
+    type Output = R;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+/// Struct for emitting values for each listeners
+#[derive(Debug)]
+pub struct EventEmitter<'a, T: ForLifetime> {
+    cursor: CursorMut<'a, NodeTypes<T>>,
+    meta: EventMeta,
+}
+
+impl<T: ForLifetime> EventEmitter<'_, T> {
+    /// Metadata of emitting event
+    pub const fn meta(&self) -> EventMeta {
+        self.meta
+    }
+
+    /// Emit event to next listener
+    pub fn emit_next(&mut self, event: T::Of<'_>) -> Option<()> {
+        let node = self.cursor.protected_mut()?;
+
+        // SAFETY: Listener closure pointer is valid
+        if unsafe { !node.poll(event, self.meta) } {
+            return None;
+        }
+
+        self.cursor.move_next();
+
+        Some(())
+    }
+}
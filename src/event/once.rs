@@ -0,0 +1,94 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use higher_kinded_types::ForLifetime;
+
+use super::{
+    future::{EventFnFuture, Listener},
+    ControlFlow, EventSource,
+};
+
+pin_project_lite::pin_project!(
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    /// Future created with [`EventSource::once`]
+    pub struct OnceFuture<'a, F, T: ForLifetime, R> {
+        #[pin]
+        inner: EventFnFuture<'a, OnceListener<F, R>, T>,
+        finished: bool,
+    }
+);
+
+impl<'a, T: ForLifetime, F, R> OnceFuture<'a, F, T, R> {
+    pub(super) const fn new(source: &'a EventSource<T>, listener: F) -> Self {
+        Self {
+            inner: EventFnFuture::new(source, OnceListener::new(listener)),
+            finished: false,
+        }
+    }
+}
+
+impl<'a, T: ForLifetime, F, R> Future for OnceFuture<'a, F, T, R>
+where
+    F: FnMut(T::Of<'_>, &mut ControlFlow) -> Option<R> + Send,
+    R: Send,
+{
+    type Output = Option<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if *this.finished {
+            panic!("OnceFuture polled after completion");
+        }
+
+        ready!(this.inner.as_mut().poll_listener(cx));
+        *this.finished = true;
+
+        Poll::Ready(this.inner.unlink().take())
+    }
+}
+
+/// Listener storing output inline, until output is taken
+#[derive(Debug)]
+pub(super) struct OnceListener<F, R> {
+    listener: F,
+    out: Option<R>,
+}
+
+impl<F, R> OnceListener<F, R> {
+    pub const fn new(listener: F) -> Self {
+        Self {
+            listener,
+            out: None,
+        }
+    }
+
+    pub fn take(&mut self) -> Option<R> {
+        self.out.take()
+    }
+}
+
+impl<T: ForLifetime, F, R> Listener<T> for OnceListener<F, R>
+where
+    F: FnMut(T::Of<'_>, &mut ControlFlow) -> Option<R>,
+{
+    fn call(&mut self, event: T::Of<'_>, flow: &mut ControlFlow) {
+        if flow.done() {
+            return;
+        }
+
+        if let output @ Some(_) = (self.listener)(event, flow) {
+            self.out = output;
+            flow.set_done();
+        }
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    fmt::{self, Debug},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use futures_lite::{future, Stream};
+use higher_kinded_types::ForLifetime;
+use parking_lot::{Mutex, MutexGuard};
+
+use crate::{executor::executor_handle, spawn_ui_task, task::Task};
+
+use super::{buffered::Queue, ControlFlow, EventSource, Overflow};
+
+impl<T: ForLifetime + 'static> EventSource<T> {
+    /// Listen events on runtime thread, for futures running on other executors
+    ///
+    /// Listener is registered by a task on runtime thread, so returned [`Remote`] can be awaited on any thread without polling it there.
+    /// Events are converted into owned values with `map` and queued up to `capacity`, handled by `overflow` once full.
+    /// Can be called on any thread while runtime is running.
+    ///
+    /// With [`Overflow::Panic`], listener stops once queue overflows and the panic is raised by [`Remote::next`] on consumer side,
+    /// instead of on runtime thread.
+    /// ```ignore
+    /// let mut resized = window().remote(8, Overflow::Coalesce, |(_, event)| match event {
+    ///     WindowEvent::Resized(size) => Some(*size),
+    ///     _ => None,
+    /// });
+    ///
+    /// tokio::spawn(async move {
+    ///     while let Some(size) = resized.next().await {
+    ///         resize_swapchain(size);
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero
+    pub fn remote<F, R>(&'static self, capacity: usize, overflow: Overflow, mut map: F) -> Remote<R>
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        assert!(capacity > 0, "Buffer capacity must be non zero");
+
+        let queue = Arc::new(Mutex::new(Queue::new()));
+
+        let task = spawn_close_on_exit(
+            self,
+            {
+                let queue = queue.clone();
+
+                move |event, flow| {
+                    if let Some(event) = map(event) {
+                        if !queue.lock().push_or_defer(event, capacity, overflow) {
+                            flow.set_done();
+                        }
+                    }
+                }
+            },
+            {
+                let queue = queue.clone();
+                move || queue.lock().close()
+            },
+        );
+
+        Remote { queue, _task: task }
+    }
+}
+
+/// Listen `source` on a ui task until listener is done or runtime exits, then call `on_close`
+pub(super) fn spawn_close_on_exit<T: ForLifetime + 'static>(
+    source: &'static EventSource<T>,
+    on_event: impl FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'static,
+    on_close: impl FnOnce() + Send + 'static,
+) -> Task<()> {
+    spawn_ui_task(async move {
+        // Called once listener stops, including when runtime exits
+        let _closing = OnDrop(Some(on_close));
+
+        // Task is never dropped by runtime while listening, so stop once it exits
+        executor_handle()
+            .exit_token()
+            .run_until_cancelled(source.on(on_event))
+            .await;
+    })
+}
+
+struct OnDrop<F: FnOnce()>(Option<F>);
+
+impl<F: FnOnce()> Drop for OnDrop<F> {
+    fn drop(&mut self) {
+        if let Some(f) = self.0.take() {
+            f();
+        }
+    }
+}
+
+/// Subscription created with [`EventSource::remote`], which can be moved to other threads
+///
+/// Stops listening once dropped.
+#[must_use = "streams do nothing unless polled"]
+pub struct Remote<R> {
+    queue: Arc<Mutex<Queue<R>>>,
+    _task: Task<()>,
+}
+
+impl<R> Remote<R> {
+    /// Wait for next queued event
+    ///
+    /// Returns [`None`] once every queued event is taken after listener stopped, like when runtime exited.
+    ///
+    /// # Panics
+    /// Panics if queue overflowed with [`Overflow::Panic`]
+    pub async fn next(&mut self) -> Option<R> {
+        future::poll_fn(|cx| self.poll_event(cx)).await
+    }
+
+    /// Take next queued event without waiting
+    ///
+    /// # Panics
+    /// Panics if queue overflowed with [`Overflow::Panic`]
+    pub fn try_next(&mut self) -> Option<R> {
+        self.lock_queue().events.pop_front()
+    }
+
+    /// Number of queued events
+    pub fn len(&self) -> usize {
+        self.queue.lock().events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.lock().events.is_empty()
+    }
+
+    /// Number of events dropped or coalesced by overflow so far
+    pub fn overflowed(&self) -> usize {
+        self.queue.lock().overflowed
+    }
+
+    /// Check if listener stopped
+    pub fn is_closed(&self) -> bool {
+        self.queue.lock().closed
+    }
+
+    /// Lock queue for taking events, continuing overflow panic of listener
+    fn lock_queue(&self) -> MutexGuard<'_, Queue<R>> {
+        let queue = self.queue.lock();
+        if let Some(capacity) = queue.overflow_panic {
+            drop(queue);
+            panic!("Event buffer overflowed, capacity: {capacity}");
+        }
+
+        queue
+    }
+
+    fn poll_event(&self, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        let mut queue = self.lock_queue();
+        if let Some(event) = queue.events.pop_front() {
+            return Poll::Ready(Some(event));
+        }
+
+        if queue.closed {
+            return Poll::Ready(None);
+        }
+
+        queue.register(cx.waker());
+        Poll::Pending
+    }
+}
+
+impl<R> Stream for Remote<R> {
+    type Item = R;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        self.poll_event(cx)
+    }
+}
+
+impl<R> Debug for Remote<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Remote")
+            .field("len", &self.len())
+            .field("overflowed", &self.overflowed())
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * Created on Fri Sep 08 2023
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{cell::UnsafeCell, marker::PhantomPinned, pin::Pin, ptr::NonNull};
+
+#[cfg(debug_assertions)]
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+pin_project_lite::pin_project! {
+    #[project(!Unpin)]
+    #[derive(Debug)]
+    pub struct Sealed<T> {
+        // Dropped before inner value, so it is poisoned first
+        liveness: Liveness,
+        #[pin]
+        inner: Inner<T>,
+    }
+}
+
+// SAFETY: Inner value is only accessed through `Pin<&mut Sealed<T>>` or pointer taken from it, never through shared reference
+unsafe impl<T: Send> Sync for Sealed<T> {}
+
+/// Inner value accessed only through raw pointer while pinned.
+/// References to `!Unpin` value do not claim uniqueness, so getting pointer again does not invalidate one held by listener list.
+#[derive(Debug)]
+pub struct Inner<T> {
+    value: UnsafeCell<T>,
+    _pinned: PhantomPinned,
+}
+
+impl<T> Sealed<T> {
+    pub const fn new(inner: T) -> Self {
+        Self {
+            liveness: Liveness::new(),
+            inner: Inner {
+                value: UnsafeCell::new(inner),
+                _pinned: PhantomPinned,
+            },
+        }
+    }
+
+    /// Token checking inner value is not dropped yet, on debug builds
+    pub fn token(self: Pin<&mut Self>) -> LivenessToken {
+        self.project().liveness.token()
+    }
+
+    /// Must not be called while pointer from [`Sealed::get_ptr_mut`] is in use
+    pub fn inner_mut(self: Pin<&mut Self>) -> &mut T {
+        // SAFETY: Mutable access is exclusive through pinned mutable reference
+        unsafe { &mut *self.project().inner.value.get() }
+    }
+
+    pub fn get_ptr_mut(self: Pin<&mut Self>) -> NonNull<T> {
+        // SAFETY: Pointer of UnsafeCell is not null
+        unsafe { NonNull::new_unchecked(self.project().inner.value.get()) }
+    }
+}
+
+/// Poison flag of a value, set once dropped. Checked only on debug builds.
+#[derive(Debug)]
+struct Liveness {
+    #[cfg(debug_assertions)]
+    alive: Option<Arc<AtomicBool>>,
+}
+
+impl Liveness {
+    const fn new() -> Self {
+        Self {
+            #[cfg(debug_assertions)]
+            alive: None,
+        }
+    }
+
+    fn token(&mut self) -> LivenessToken {
+        LivenessToken {
+            #[cfg(debug_assertions)]
+            alive: self
+                .alive
+                .get_or_insert_with(|| Arc::new(AtomicBool::new(true)))
+                .clone(),
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for Liveness {
+    fn drop(&mut self) {
+        if let Some(ref alive) = self.alive {
+            alive.store(false, Ordering::Release);
+        }
+    }
+}
+
+/// Token of a [`Sealed`] value, for checking it is still alive
+#[derive(Debug)]
+pub struct LivenessToken {
+    #[cfg(debug_assertions)]
+    alive: Arc<AtomicBool>,
+}
+
+impl LivenessToken {
+    /// Abort if value is dropped, on debug builds
+    #[inline]
+    pub fn check(&self, what: &str) {
+        #[cfg(debug_assertions)]
+        if !self.alive.load(Ordering::Acquire) {
+            abort(what);
+        }
+
+        #[cfg(not(debug_assertions))]
+        let _ = what;
+    }
+}
+
+/// Abort loudly, as unwinding with dangling pointer is not safe either
+#[cfg(debug_assertions)]
+#[cold]
+pub fn abort(what: &str) -> ! {
+    eprintln!("winit-runtime: {what}. This is a bug of winit-runtime, aborting");
+    std::process::abort()
+}
@@ -0,0 +1,403 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread,
+    time::Duration,
+};
+
+use futures_lite::future;
+use parking_lot::Mutex;
+
+use crate::emit;
+
+type Source = crate::EventSource!(u32);
+
+/// Waker counting wakes
+#[derive(Debug, Default)]
+struct CountWaker(AtomicUsize);
+
+impl CountWaker {
+    fn waker() -> (Arc<Self>, Waker) {
+        let count = Arc::new(Self::default());
+
+        (count.clone(), Waker::from(count))
+    }
+
+    fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl Wake for CountWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn poll<F: Future<Output = ()> + ?Sized>(fut: Pin<&mut F>, waker: &Waker) -> Poll<()> {
+    fut.poll(&mut Context::from_waker(waker))
+}
+
+/// Three listeners recording last event, the one at `position` panics on first event
+fn panic_at(position: usize) {
+    let source = Source::new();
+    let seen: [_; 3] = Default::default();
+    let (_, waker) = CountWaker::waker();
+
+    let listener = |index: usize| {
+        let seen: &AtomicUsize = &seen[index];
+
+        source.on(move |event, flow| {
+            assert!(!flow.done());
+            seen.store(event as usize, Ordering::SeqCst);
+
+            if index == position {
+                panic!("listener {index} panicked");
+            }
+        })
+    };
+
+    let mut first = pin!(listener(0));
+    let mut middle = pin!(listener(1));
+    let mut last = pin!(listener(2));
+    let mut listeners: [Pin<&mut dyn Future<Output = ()>>; 3] =
+        [first.as_mut(), middle.as_mut(), last.as_mut()];
+
+    for listener in &mut listeners {
+        assert!(poll(listener.as_mut(), &waker).is_pending());
+    }
+
+    // Panic is caught, so every listener receives the event
+    emit!(source, 1);
+    assert!(!source.is_emitting());
+    for (index, seen) in seen.iter().enumerate() {
+        assert_eq!(seen.load(Ordering::SeqCst), 1, "listener {index}");
+    }
+
+    // Panic continues on the task owning panicked listener only
+    for (index, listener) in listeners.iter_mut().enumerate() {
+        let polled = panic::catch_unwind(AssertUnwindSafe(|| poll(listener.as_mut(), &waker)));
+
+        if index == position {
+            assert!(polled.is_err());
+        } else {
+            assert!(matches!(polled, Ok(Poll::Pending)), "listener {index}");
+        }
+    }
+
+    // Panicked listener is never called again, others stay subscribed and not done
+    emit!(source, 2);
+    for (index, seen) in seen.iter().enumerate() {
+        let expected = if index == position { 1 } else { 2 };
+        assert_eq!(seen.load(Ordering::SeqCst), expected, "listener {index}");
+    }
+
+    // Source accepts new listener, which starts not done
+    let added = AtomicUsize::new(0);
+    let mut new = pin!(source.on(|event, flow| {
+        assert!(!flow.done());
+        added.store(event as usize, Ordering::SeqCst);
+        flow.set_done();
+    }));
+    assert!(poll(new.as_mut(), &waker).is_pending());
+
+    emit!(source, 3);
+    assert_eq!(added.load(Ordering::SeqCst), 3);
+    assert!(poll(new.as_mut(), &waker).is_ready());
+
+    for (index, listener) in listeners.iter_mut().enumerate() {
+        if index != position {
+            assert!(poll(listener.as_mut(), &waker).is_pending());
+        }
+    }
+}
+
+#[test]
+fn panic_in_first_listener() {
+    panic_at(0);
+}
+
+#[test]
+fn panic_in_middle_listener() {
+    panic_at(1);
+}
+
+#[test]
+fn panic_in_last_listener() {
+    panic_at(2);
+}
+
+#[test]
+fn repoll_with_new_waker() {
+    let source = Source::new();
+    let (old_count, old) = CountWaker::waker();
+    let (new_count, new) = CountWaker::waker();
+
+    let mut listener = pin!(source.on(|_, flow| flow.set_done()));
+    assert!(poll(listener.as_mut(), &old).is_pending());
+    assert!(poll(listener.as_mut(), &new).is_pending());
+
+    emit!(source, 1);
+    assert_eq!(old_count.count(), 0);
+    assert_eq!(new_count.count(), 1);
+    assert!(poll(listener.as_mut(), &new).is_ready());
+}
+
+#[test]
+fn subscribe_inside_listener() {
+    let source = Source::new();
+    let (wakes, waker) = CountWaker::waker();
+
+    let nested_seen = AtomicUsize::new(0);
+    let nested = Mutex::new(None);
+
+    let mut outer = pin!(source.on(|event, _| {
+        if event != 1 {
+            return;
+        }
+
+        // Registering on same source while emitting is deferred instead of deadlocking
+        let mut listener = Box::pin(source.on(|event, _| {
+            nested_seen.store(event as usize, Ordering::SeqCst);
+        }));
+        assert!(poll(listener.as_mut(), &waker).is_pending());
+        *nested.lock() = Some(listener);
+    }));
+    assert!(poll(outer.as_mut(), &waker).is_pending());
+
+    emit!(source, 1);
+    assert_eq!(source.listener_count(), 1);
+    assert_eq!(wakes.count(), 1);
+
+    // Polled again once woken, registered after emit and observes from next emit
+    let mut listener = nested.lock().take().unwrap();
+    assert!(poll(listener.as_mut(), &waker).is_pending());
+    assert_eq!(source.listener_count(), 2);
+    assert_eq!(nested_seen.load(Ordering::SeqCst), 0);
+
+    emit!(source, 2);
+    assert_eq!(nested_seen.load(Ordering::SeqCst), 2);
+}
+
+/// Task polled synchronously on wake, like executor running woken task inline.
+/// Wakes while polling are left for caller, as task polled inside of emit is woken again until emit finishes.
+struct InlineTask {
+    future: Mutex<Option<Pin<Box<dyn Future<Output = ()> + Send + 'static>>>>,
+    rewoken: AtomicBool,
+}
+
+impl InlineTask {
+    fn new(future: impl Future<Output = ()> + Send + 'static) -> Arc<Self> {
+        Arc::new(Self {
+            future: Mutex::new(Some(Box::pin(future))),
+            rewoken: AtomicBool::new(false),
+        })
+    }
+
+    /// Poll once, or mark woken if it is being polled already
+    fn run(self: &Arc<Self>) {
+        let Some(mut future) = self.future.try_lock() else {
+            self.rewoken.store(true, Ordering::SeqCst);
+            return;
+        };
+        self.rewoken.store(false, Ordering::SeqCst);
+
+        if let Some(fut) = future.as_mut() {
+            if poll(fut.as_mut(), &Waker::from(self.clone())).is_ready() {
+                *future = None;
+            }
+        }
+    }
+}
+
+impl Wake for InlineTask {
+    fn wake(self: Arc<Self>) {
+        self.run();
+    }
+}
+
+#[test]
+fn subscribe_from_task_woken_inside_listener() {
+    static SOURCE: Source = Source::new();
+    static SEEN: AtomicUsize = AtomicUsize::new(0);
+
+    let (_, waker) = CountWaker::waker();
+
+    // Task subscribes once woken by first listener
+    let trigger = Arc::new(AtomicBool::new(false));
+    let task = InlineTask::new({
+        let trigger = trigger.clone();
+
+        async move {
+            wait_flag(&trigger).await;
+            SOURCE
+                .on(|event, _| {
+                    SEEN.store(event as usize, Ordering::SeqCst);
+                })
+                .await;
+        }
+    });
+    task.run();
+
+    let mut first = pin!(SOURCE.on({
+        let task = task.clone();
+
+        move |_, flow| {
+            if !flow.done() {
+                trigger.store(true, Ordering::SeqCst);
+                Waker::from(task.clone()).wake();
+                flow.set_done();
+            }
+        }
+    }));
+    assert!(poll(first.as_mut(), &waker).is_pending());
+
+    // Task is polled inside of emit, then registered once emit finishes
+    emit!(SOURCE, 1);
+    assert_eq!(SEEN.load(Ordering::SeqCst), 0);
+    assert!(task.rewoken.load(Ordering::SeqCst));
+
+    task.run();
+    assert_eq!(SOURCE.listener_count(), 2);
+
+    emit!(SOURCE, 2);
+    assert_eq!(SEEN.load(Ordering::SeqCst), 2);
+}
+
+/// Wait until flag is set, polling once per wake
+async fn wait_flag(flag: &AtomicBool) {
+    future::poll_fn(|_| {
+        if flag.load(Ordering::SeqCst) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+#[test]
+fn drop_listener_before_emit() {
+    let source = Source::new();
+    let called = AtomicUsize::new(0);
+    let (_, waker) = CountWaker::waker();
+
+    // Never registered
+    drop(source.on(|_, _| {
+        called.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    // Registered and unlinked before emit
+    {
+        let mut listener = pin!(source.on(|_, _| {
+            called.fetch_add(1, Ordering::SeqCst);
+        }));
+        assert!(poll(listener.as_mut(), &waker).is_pending());
+        assert_eq!(source.listener_count(), 1);
+    }
+    assert_eq!(source.listener_count(), 0);
+
+    emit!(source, 1);
+    assert_eq!(called.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn drop_listener_after_emit() {
+    let source = Source::new();
+    let called = AtomicUsize::new(0);
+    let (_, waker) = CountWaker::waker();
+
+    let mut kept = pin!(source.on(|_, _| {
+        called.fetch_add(1, Ordering::SeqCst);
+    }));
+    assert!(poll(kept.as_mut(), &waker).is_pending());
+
+    {
+        let mut dropped = pin!(source.on(|_, flow| {
+            called.fetch_add(1, Ordering::SeqCst);
+            flow.set_done();
+        }));
+        assert!(poll(dropped.as_mut(), &waker).is_pending());
+
+        emit!(source, 1);
+        assert_eq!(called.load(Ordering::SeqCst), 2);
+    }
+
+    // Only nodes of alive closures are left
+    emit!(source, 2);
+    assert_eq!(called.load(Ordering::SeqCst), 3);
+    assert_eq!(source.listener_count(), 1);
+}
+
+#[test]
+fn drop_listener_mid_emit() {
+    let source = Source::new();
+    let seen = AtomicUsize::new(0);
+    let (_, waker) = CountWaker::waker();
+
+    let mut dropped = Box::pin(source.on(|event, _| {
+        seen.store(event as usize, Ordering::SeqCst);
+    }));
+
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel::<()>();
+
+        let mut first = pin!(source.on(|event, _| {
+            if event == 1 {
+                // Other thread drops next listener while this emit is running
+                sender.send(()).unwrap();
+                thread::sleep(Duration::from_millis(10));
+            }
+        }));
+        assert!(poll(first.as_mut(), &waker).is_pending());
+        assert!(poll(dropped.as_mut(), &waker).is_pending());
+
+        let dropping = scope.spawn(move || {
+            receiver.recv().unwrap();
+            drop(dropped);
+        });
+
+        // Dropping waits until emit finishes, so node is still valid while emitting
+        emit!(source, 1);
+        dropping.join().unwrap();
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+        assert_eq!(source.listener_count(), 1);
+
+        emit!(source, 2);
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+    });
+}
+
+#[test]
+fn drop_unregistered_listener_inside_listener() {
+    let source = Source::new();
+    let (wakes, waker) = CountWaker::waker();
+
+    let mut outer = pin!(source.on(|_, _| {
+        // Never registered, so dropping needs no list lock
+        let mut nested = pin!(source.on(|_, _| unreachable!()));
+        assert!(poll(nested.as_mut(), &waker).is_pending());
+    }));
+    assert!(poll(outer.as_mut(), &waker).is_pending());
+
+    emit!(source, 1);
+    emit!(source, 2);
+    assert_eq!(wakes.count(), 2);
+    assert_eq!(source.listener_count(), 1);
+}
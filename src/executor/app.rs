@@ -0,0 +1,140 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! winit [`ApplicationHandler`] driving executor
+
+use std::mem;
+
+use futures_lite::Future;
+use winit::{
+    application::ApplicationHandler,
+    event::{DeviceEvent, DeviceId, Event, StartCause, WindowEvent},
+    event_loop::{EventLoop, EventLoopProxy},
+    window::WindowId,
+};
+
+use crate::config::RuntimeConfig;
+
+use super::{
+    event::ExecutorEvent, init_handle, proxy::EventProxy, EventLoopTarget, Executor, EL_TARGET,
+};
+
+/// Application running executor, initialized once event loop starts
+///
+/// Every callback is forwarded to executor as [`Event`], so event sources stay same as closure based event loop.
+pub(super) struct Runtime<F> {
+    state: State<F>,
+}
+
+enum State<F> {
+    Pending {
+        proxy: EventLoopProxy<ExecutorEvent>,
+        config: RuntimeConfig,
+        setup: F,
+    },
+
+    Running(Executor),
+
+    /// Setup is running, or panicked
+    Initializing,
+}
+
+impl<F, Fut> Runtime<F>
+where
+    F: FnOnce(&EventLoopTarget) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    /// Create runtime running on given event loop
+    ///
+    /// # Safety
+    /// Runtime must be used on current thread and main Future must outlive Runtime
+    pub unsafe fn new(
+        event_loop: &EventLoop<ExecutorEvent>,
+        config: RuntimeConfig,
+        setup: F,
+    ) -> Self {
+        Self {
+            state: State::Pending {
+                proxy: event_loop.create_proxy(),
+                config,
+                setup,
+            },
+        }
+    }
+
+    fn dispatch(&mut self, target: &EventLoopTarget, event: Event<ExecutorEvent>) {
+        if let State::Pending { .. } = self.state {
+            self.init(target);
+        }
+
+        if let State::Running(ref mut executor) = self.state {
+            executor.on_event(event, target);
+        }
+    }
+
+    /// Start executor and spawn main task, on first event
+    fn init(&mut self, target: &EventLoopTarget) {
+        let State::Pending {
+            proxy,
+            config,
+            setup,
+        } = mem::replace(&mut self.state, State::Initializing)
+        else {
+            return;
+        };
+
+        let handle = init_handle(EventProxy::EventLoop(proxy), config);
+
+        let main = EL_TARGET.set(target, || setup(target));
+        // SAFETY: Guaranteed by Runtime::new
+        let (executor, runnable) = unsafe { Executor::new(handle, main) };
+
+        EL_TARGET.set(target, move || runnable.run());
+        self.state = State::Running(executor);
+    }
+}
+
+impl<F, Fut> ApplicationHandler<ExecutorEvent> for Runtime<F>
+where
+    F: FnOnce(&EventLoopTarget) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    fn new_events(&mut self, target: &EventLoopTarget, cause: StartCause) {
+        self.dispatch(target, Event::NewEvents(cause));
+    }
+
+    fn resumed(&mut self, target: &EventLoopTarget) {
+        self.dispatch(target, Event::Resumed);
+    }
+
+    fn user_event(&mut self, target: &EventLoopTarget, event: ExecutorEvent) {
+        self.dispatch(target, Event::UserEvent(event));
+    }
+
+    fn window_event(&mut self, target: &EventLoopTarget, window_id: WindowId, event: WindowEvent) {
+        self.dispatch(target, Event::WindowEvent { window_id, event });
+    }
+
+    fn device_event(&mut self, target: &EventLoopTarget, device_id: DeviceId, event: DeviceEvent) {
+        self.dispatch(target, Event::DeviceEvent { device_id, event });
+    }
+
+    fn about_to_wait(&mut self, target: &EventLoopTarget) {
+        self.dispatch(target, Event::AboutToWait);
+    }
+
+    fn suspended(&mut self, target: &EventLoopTarget) {
+        self.dispatch(target, Event::Suspended);
+    }
+
+    fn exiting(&mut self, target: &EventLoopTarget) {
+        self.dispatch(target, Event::LoopExiting);
+    }
+
+    fn memory_warning(&mut self, target: &EventLoopTarget) {
+        self.dispatch(target, Event::MemoryWarning);
+    }
+}
@@ -0,0 +1,311 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    cell::Cell,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+use futures_lite::{Future, FutureExt};
+use instant::Duration;
+#[cfg(feature = "device-events")]
+use winit::event_loop::DeviceEvents;
+use winit::{
+    error::EventLoopError,
+    event_loop::{EventLoop, EventLoopBuilder},
+};
+
+use crate::{
+    config::{ConfigChange, DispatchOrder, PollBudget, PowerProfile, RedrawPolicy, RuntimeConfig},
+    timer::Clock,
+};
+
+use super::{
+    app::Runtime,
+    event::ExecutorEvent,
+    headless::{self, Backend},
+    EventLoopTarget, RunError,
+};
+
+/// Builder for starting runtime with initial configuration
+///
+/// Options are applied before main Future is polled, so no [`changed`](crate::config::changed) events are emitted for them.
+/// Every option can be changed later using [`ExecutorHandle::config`](super::handle::ExecutorHandle::config).
+/// ```ignore
+/// RuntimeBuilder::new()
+///     .poll_budget(64)
+///     .exit_on_last_window_close(true)
+///     .run(async_main())?;
+/// ```
+pub struct RuntimeBuilder {
+    config: RuntimeConfig,
+    event_loop: EventLoopBuilder<ExecutorEvent>,
+
+    #[cfg(feature = "device-events")]
+    device_filter: Option<DeviceEvents>,
+}
+
+impl RuntimeBuilder {
+    /// Create builder with default configuration
+    pub fn new() -> Self {
+        Self {
+            config: RuntimeConfig::new(),
+            event_loop: EventLoop::with_user_event(),
+
+            #[cfg(feature = "device-events")]
+            device_filter: None,
+        }
+    }
+
+    /// See [`RuntimeConfig::redraw_policy`]
+    pub fn redraw_policy(mut self, policy: RedrawPolicy) -> Self {
+        self.config.preset(ConfigChange::RedrawPolicy(policy));
+        self
+    }
+
+    /// See [`RuntimeConfig::power_profile`]
+    pub fn power_profile(mut self, profile: PowerProfile) -> Self {
+        self.config.preset(ConfigChange::PowerProfile(profile));
+        self
+    }
+
+    /// See [`RuntimeConfig::exit_on_last_window_close`]
+    pub fn exit_on_last_window_close(mut self, exit: bool) -> Self {
+        self.config
+            .preset(ConfigChange::ExitOnLastWindowClose(exit));
+        self
+    }
+
+    /// See [`RuntimeConfig::background`]
+    pub fn background(mut self, background: bool) -> Self {
+        self.config.preset(ConfigChange::Background(background));
+        self
+    }
+
+    /// See [`RuntimeConfig::poll_budget`]. Number of tasks can be given as budget.
+    pub fn poll_budget(mut self, budget: impl Into<PollBudget>) -> Self {
+        self.config.preset(ConfigChange::PollBudget(budget.into()));
+        self
+    }
+
+    /// See [`RuntimeConfig::timer_slack`]
+    pub fn timer_slack(mut self, slack: Duration) -> Self {
+        self.config.preset(ConfigChange::TimerSlack(slack));
+        self
+    }
+
+    /// See [`RuntimeConfig::pause_occluded`]
+    pub fn pause_occluded(mut self, pause: bool) -> Self {
+        self.config.preset(ConfigChange::PauseOccluded(pause));
+        self
+    }
+
+    /// See [`RuntimeConfig::teardown_deadline`]
+    pub fn teardown_deadline(mut self, deadline: Duration) -> Self {
+        self.config
+            .preset(ConfigChange::TeardownDeadline(Some(deadline)));
+        self
+    }
+
+    /// See [`RuntimeConfig::metrics_enabled`]
+    pub fn metrics(mut self, enabled: bool) -> Self {
+        self.config.preset(ConfigChange::Metrics(enabled));
+        self
+    }
+
+    /// See [`RuntimeConfig::stall_threshold`]
+    pub fn stall_threshold(mut self, threshold: Duration) -> Self {
+        self.config
+            .preset(ConfigChange::StallThreshold(Some(threshold)));
+        self
+    }
+
+    /// See [`RuntimeConfig::report_leaked_tasks`]
+    pub fn report_leaked_tasks(mut self, report: bool) -> Self {
+        self.config.preset(ConfigChange::ReportLeakedTasks(report));
+        self
+    }
+
+    /// See [`RuntimeConfig::dispatch_order`]
+    pub fn dispatch_order(mut self, order: DispatchOrder) -> Self {
+        self.config.preset(ConfigChange::DispatchOrder(order));
+        self
+    }
+
+    /// See [`RuntimeConfig::with_clock`]
+    pub fn clock(mut self, clock: &'static dyn Clock) -> Self {
+        self.config = self.config.with_clock(clock);
+        self
+    }
+
+    /// See [`ExecutorHandle::device_event_filter`](super::handle::ExecutorHandle::device_event_filter)
+    #[cfg(feature = "device-events")]
+    pub fn device_event_filter(mut self, filter: DeviceEvents) -> Self {
+        self.device_filter = Some(filter);
+        self
+    }
+
+    /// Customize [`EventLoopBuilder`], for platform specific attributes
+    ///
+    /// See [`run_with`](super::run_with)
+    pub fn event_loop(mut self, f: impl FnOnce(&mut EventLoopBuilder<ExecutorEvent>)) -> Self {
+        f(&mut self.event_loop);
+        self
+    }
+
+    /// Start runtime
+    ///
+    /// See [`run`](super::run)
+    pub fn run(self, main: impl Future<Output = ()>) -> Result<(), EventLoopError> {
+        self.run_with_setup(|_| main)
+    }
+
+    /// Start runtime, returning output of the main Future
+    ///
+    /// See [`run_result`](super::run_result)
+    pub fn run_result<T>(self, main: impl Future<Output = T>) -> Result<T, RunError> {
+        let output = Cell::new(None);
+
+        self.run(async {
+            output.set(Some(AssertUnwindSafe(main).catch_unwind().await));
+        })?;
+
+        match output.into_inner() {
+            Some(Ok(output)) => Ok(output),
+            Some(Err(payload)) => panic::resume_unwind(payload),
+            None => Err(RunError::Exited),
+        }
+    }
+
+    /// Start runtime without event loop, running only tasks and timers
+    ///
+    /// See [`run_headless_timers`](super::run_headless_timers). Event loop customization of this builder is ignored.
+    pub fn run_headless_timers<T>(self, main: impl Future<Output = T>) -> Result<T, RunError> {
+        let output = Cell::new(None);
+
+        headless::run(Backend::Timers, self.config, async {
+            output.set(Some(AssertUnwindSafe(main).catch_unwind().await));
+        });
+
+        match output.into_inner() {
+            Some(Ok(output)) => Ok(output),
+            Some(Err(payload)) => panic::resume_unwind(payload),
+            None => Err(RunError::Exited),
+        }
+    }
+
+    /// Start runtime, with synchronous setup once event loop starts
+    ///
+    /// See [`run_with_setup`](super::run_with_setup)
+    pub fn run_with_setup<Fut>(
+        self,
+        setup: impl FnOnce(&EventLoopTarget) -> Fut,
+    ) -> Result<(), EventLoopError>
+    where
+        Fut: Future<Output = ()>,
+    {
+        let (event_loop, config, setup) = self.build(setup)?;
+
+        // SAFETY: Runtime is dropped on same function, it does not need to be Send and task and references to Future outlive event loop
+        let mut runtime = unsafe { Runtime::new(&event_loop, config, setup) };
+
+        event_loop.run_app(&mut runtime)
+    }
+
+    /// Start runtime on web
+    ///
+    /// See [`run_web`](super::run_web)
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_web(self, main: impl Future<Output = ()> + 'static) -> Result<(), EventLoopError> {
+        use winit::platform::web::EventLoopExtWebSys;
+
+        let (event_loop, config, setup) = self.build(|_| main)?;
+
+        // SAFETY: Runtime does not need to be Send as web is single threaded and main Future is 'static
+        let runtime = unsafe { Runtime::new(&event_loop, config, setup) };
+
+        event_loop.spawn_app(runtime);
+
+        Ok(())
+    }
+
+    /// Start runtime on existing event loop, returning once it exits so runtime can be started again on same event loop
+    ///
+    /// For plugin hosts and test suites running runtime multiple times in one process, as event loop cannot be created twice.
+    /// Event loop customization of this builder is ignored. State of previous run like tasks and registered windows is dropped when it exits.
+    /// ```ignore
+    /// let mut event_loop = EventLoop::with_user_event().build()?;
+    ///
+    /// RuntimeBuilder::new().run_on_demand(&mut event_loop, first())?;
+    /// RuntimeBuilder::new().run_on_demand(&mut event_loop, second())?;
+    /// ```
+    #[cfg(not(any(target_arch = "wasm32", target_os = "ios")))]
+    pub fn run_on_demand(
+        self,
+        event_loop: &mut EventLoop<ExecutorEvent>,
+        main: impl Future<Output = ()>,
+    ) -> Result<(), EventLoopError> {
+        use winit::platform::run_on_demand::EventLoopExtRunOnDemand;
+
+        let (config, setup) = self.into_setup(|_| main);
+
+        // SAFETY: Runtime is dropped once event loop returns, it does not need to be Send and task and references to Future outlive event loop
+        let mut runtime = unsafe { Runtime::new(event_loop, config, setup) };
+
+        event_loop.run_app_on_demand(&mut runtime)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn build<Fut>(
+        mut self,
+        setup: impl FnOnce(&EventLoopTarget) -> Fut,
+    ) -> Result<
+        (
+            EventLoop<ExecutorEvent>,
+            RuntimeConfig,
+            impl FnOnce(&EventLoopTarget) -> Fut,
+        ),
+        EventLoopError,
+    > {
+        let event_loop = self.event_loop.build()?;
+        let (config, setup) = self.into_setup(setup);
+
+        Ok((event_loop, config, setup))
+    }
+
+    /// Take configuration, and wrap setup to apply options of the handle
+    fn into_setup<Fut>(
+        self,
+        setup: impl FnOnce(&EventLoopTarget) -> Fut,
+    ) -> (RuntimeConfig, impl FnOnce(&EventLoopTarget) -> Fut) {
+        #[cfg(feature = "device-events")]
+        let device_filter = self.device_filter;
+
+        (self.config, move |target: &EventLoopTarget| {
+            #[cfg(feature = "device-events")]
+            if let Some(filter) = device_filter {
+                super::executor_handle().set_device_event_filter(filter);
+            }
+
+            setup(target)
+        })
+    }
+}
+
+impl Default for RuntimeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RuntimeBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuntimeBuilder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
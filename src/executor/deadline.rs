@@ -0,0 +1,61 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Deadline tasks for render-critical work
+
+use std::panic::Location;
+
+use futures_lite::Future;
+use instant::{Duration, Instant};
+
+use crate::{emit, timer};
+
+/// Deadline task completed after its deadline
+///
+/// See [`ExecutorHandle::spawn_before`](super::handle::ExecutorHandle::spawn_before)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeadlineMiss {
+    pub deadline: Instant,
+    pub completed: Instant,
+
+    /// Where the task is spawned
+    pub location: &'static Location<'static>,
+}
+
+impl DeadlineMiss {
+    /// Duration the task completed late
+    pub fn lateness(&self) -> Duration {
+        self.completed.saturating_duration_since(self.deadline)
+    }
+}
+
+define_event!(
+    /// Deadline task completed after its deadline. Tasks cancelled before completion are not reported.
+    pub deadline_missed: DeadlineMiss
+);
+
+/// Report miss once future completes after deadline
+pub(super) async fn track<Fut: Future>(
+    deadline: Instant,
+    location: &'static Location<'static>,
+    fut: Fut,
+) -> Fut::Output {
+    let output = fut.await;
+
+    let completed = timer::now();
+    if completed > deadline {
+        emit!(
+            deadline_missed(),
+            DeadlineMiss {
+                deadline,
+                completed,
+                location,
+            }
+        );
+    }
+
+    output
+}
@@ -0,0 +1,70 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{error::Error, fmt};
+
+use winit::error::EventLoopError;
+
+/// Error of runtime API misuse or runtime state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RuntimeError {
+    /// Executor is not started yet
+    NotStarted,
+
+    /// Called outside of event loop thread
+    NotRuntimeThread,
+
+    /// Event loop is already closed
+    EventLoopClosed,
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuntimeError::NotStarted => f.write_str("executor is not started"),
+            RuntimeError::NotRuntimeThread => f.write_str("called outside of event loop thread"),
+            RuntimeError::EventLoopClosed => f.write_str("event loop is closed"),
+        }
+    }
+}
+
+impl Error for RuntimeError {}
+
+/// Error of [`run_result`](super::run_result)
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RunError {
+    /// Event loop failed
+    EventLoop(EventLoopError),
+
+    /// Event loop exited before main task completed
+    Exited,
+}
+
+impl From<EventLoopError> for RunError {
+    fn from(err: EventLoopError) -> Self {
+        RunError::EventLoop(err)
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::EventLoop(err) => write!(f, "event loop error: {err}"),
+            RunError::Exited => f.write_str("event loop exited before main task completed"),
+        }
+    }
+}
+
+impl Error for RunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RunError::EventLoop(err) => Some(err),
+            RunError::Exited => None,
+        }
+    }
+}
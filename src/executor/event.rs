@@ -4,12 +4,16 @@
  * Copyright (c) storycraft. Licensed under the MIT Licence.
  */
 
-use async_task::Runnable;
+use winit::{
+    event::{DeviceEvent, DeviceId, WindowEvent},
+    window::WindowId,
+};
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ExecutorEvent {
     Wake,
-    PollTask(Runnable),
+    WindowEvent(WindowId, WindowEvent),
+    DeviceEvent(DeviceId, DeviceEvent),
     Exit,
 }
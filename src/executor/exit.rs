@@ -0,0 +1,122 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    error::Error,
+    fmt::{self, Debug},
+    pin::Pin,
+};
+
+use futures_lite::Future;
+use instant::Duration;
+
+type Cleanup = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Exit request emitted on [`exit_requested`](crate::exit_requested) event source
+///
+/// Listeners can cancel the request, or add cleanup which runs before event loop exits.
+/// Listener can stop propagation to hide the request from later listeners.
+pub struct ExitRequest {
+    code: i32,
+    cancelled: bool,
+    cleanup: Vec<Cleanup>,
+}
+
+impl ExitRequest {
+    pub(super) const fn new(code: i32) -> Self {
+        Self {
+            code,
+            cancelled: false,
+            cleanup: Vec::new(),
+        }
+    }
+
+    /// Requested exit code
+    pub const fn code(&self) -> i32 {
+        self.code
+    }
+
+    /// Cancel exit, like when there is unsaved state
+    pub fn cancel(&mut self) {
+        self.cancelled = true;
+    }
+
+    pub const fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    /// Run cleanup before exiting, if request is not cancelled
+    ///
+    /// Cleanups run in order they are added.
+    pub fn defer(&mut self, cleanup: impl Future<Output = ()> + Send + 'static) {
+        self.cleanup.push(Box::pin(cleanup));
+    }
+
+    pub(super) fn into_cleanup(self) -> Option<Vec<Cleanup>> {
+        (!self.cancelled).then_some(self.cleanup)
+    }
+}
+
+impl Debug for ExitRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExitRequest")
+            .field("code", &self.code)
+            .field("cancelled", &self.cancelled)
+            .field("cleanup", &self.cleanup.len())
+            .finish()
+    }
+}
+
+/// Exit request was cancelled by a listener of [`exit_requested`](crate::exit_requested)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExitCancelled;
+
+impl fmt::Display for ExitCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("exit cancelled")
+    }
+}
+
+impl Error for ExitCancelled {}
+
+/// Aborts process if teardown does not finish before deadline, disarmed once dropped
+#[derive(Debug)]
+pub(super) struct Watchdog {
+    #[cfg(not(target_arch = "wasm32"))]
+    _armed: std::sync::mpsc::Sender<()>,
+}
+
+impl Watchdog {
+    pub fn start(deadline: Duration) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use std::{
+                process,
+                sync::mpsc::{self, RecvTimeoutError},
+                thread,
+            };
+
+            let (sender, receiver) = mpsc::channel::<()>();
+            thread::spawn(move || {
+                if let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(deadline) {
+                    eprintln!(
+                        "winit-runtime: teardown exceeded deadline of {deadline:?}, aborting"
+                    );
+                    process::abort();
+                }
+            });
+
+            Self { _armed: sender }
+        }
+
+        // Threads are not available on wasm
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = deadline;
+            Self {}
+        }
+    }
+}
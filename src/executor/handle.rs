@@ -4,89 +4,576 @@
  * Copyright (c) storycraft. Licensed under the MIT Licence.
  */
 
-use std::thread::{self, ThreadId};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    mem,
+    panic::Location,
+    sync::{
+        atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering},
+        Arc,
+    },
+    thread::{self, ThreadId},
+};
 
 use async_task::{Runnable, Task};
-use futures_intrusive::timer::TimerFuture;
-use futures_lite::Future;
-use instant::Duration;
+use concurrent_queue::ConcurrentQueue;
+use futures_lite::{future, Future};
+use instant::{Duration, Instant};
 use parking_lot::Mutex;
-use winit::event_loop::{EventLoop, EventLoopProxy};
+use winit::{event::WindowEvent, window::WindowId};
 
-use crate::timer::ExecutorTimer;
+#[cfg(feature = "device-events")]
+use winit::{
+    event::{DeviceEvent, DeviceId},
+    event_loop::DeviceEvents,
+};
 
-use super::event::ExecutorEvent;
+use crate::{
+    cancel::CancellationToken,
+    config::{ConfigChange, RuntimeConfig},
+    emit, exit_requested,
+    lifecycle::Milestones,
+    timer::{ExecutorTimer, TimerFuture, TimerStats},
+    window::WindowMetrics,
+};
+
+use super::{
+    deadline,
+    error::RuntimeError,
+    event::ExecutorEvent,
+    exit::{ExitCancelled, ExitRequest},
+    proxy::{EventProxy, SharedProxy},
+    queue::{self, Lane, Priority, RemoteQueue},
+    shutdown::{self, AfterShutdown},
+    stall::StallWatchdog,
+    tasks::{TaskRegistry, TaskSnapshot, Tracked},
+};
+
+type Posted = Box<dyn FnOnce() + Send>;
 
 /// Handle task spawning and timer
 #[derive(Debug)]
 pub struct ExecutorHandle {
     thread_id: ThreadId,
-    proxy: Mutex<EventLoopProxy<ExecutorEvent>>,
+    proxy: SharedProxy,
+    config: RuntimeConfig,
+    windows: Mutex<HashSet<WindowId>>,
+    close_guards: Mutex<HashMap<WindowId, bool>>,
+    window_states: Mutex<HashMap<WindowId, WindowState>>,
+    resumed: AtomicBool,
+    resume_generation: AtomicU64,
+    exit_code: AtomicI32,
+    exit_token: CancellationToken,
+    suspend_token: Mutex<CancellationToken>,
+    #[cfg(feature = "device-events")]
+    device_filter: Mutex<DeviceEvents>,
+    tasks: Arc<TaskRegistry>,
+    posted: ConcurrentQueue<Posted>,
+    exit_hooks: ConcurrentQueue<Posted>,
+    milestones: Milestones,
 
+    pub(super) remote: Arc<RemoteQueue>,
     pub(super) timer: ExecutorTimer,
+    pub(super) stall: StallWatchdog,
 }
 
 impl ExecutorHandle {
-    pub(crate) fn new(event_loop: &EventLoop<ExecutorEvent>) -> Self {
+    pub(crate) fn new(proxy: EventProxy, config: RuntimeConfig) -> Self {
+        let clock = config.clock();
+
         Self {
             thread_id: thread::current().id(),
-            proxy: Mutex::new(event_loop.create_proxy()),
+            proxy: SharedProxy::new(proxy),
+            config,
+            windows: Mutex::new(HashSet::new()),
+            close_guards: Mutex::new(HashMap::new()),
+            window_states: Mutex::new(HashMap::new()),
+            resumed: AtomicBool::new(false),
+            resume_generation: AtomicU64::new(0),
+            exit_code: AtomicI32::new(0),
+            exit_token: CancellationToken::new(),
+            suspend_token: Mutex::new(CancellationToken::new()),
+            #[cfg(feature = "device-events")]
+            device_filter: Mutex::new(DeviceEvents::default()),
+            tasks: Arc::new(TaskRegistry::default()),
+            posted: ConcurrentQueue::unbounded(),
+            exit_hooks: ConcurrentQueue::unbounded(),
+            milestones: Milestones::default(),
 
-            timer: ExecutorTimer::new(),
+            remote: Arc::new(RemoteQueue::new()),
+            timer: ExecutorTimer::new(clock),
+            stall: StallWatchdog::new(),
         }
     }
 
-    /// Exit event loop with exit code
+    /// Apply settings of runtime subsystems on start
+    pub(super) fn apply_settings(&self) {
+        self.apply_config(&ConfigChange::Metrics(self.config.metrics_enabled()));
+        self.apply_config(&ConfigChange::StallThreshold(self.config.stall_threshold()));
+    }
+
+    /// Adjust runtime subsystems to changed setting
+    pub(crate) fn apply_config(&self, change: &ConfigChange) {
+        match *change {
+            #[cfg(feature = "metrics")]
+            ConfigChange::Metrics(enabled) => super::metrics::set_enabled(enabled),
+
+            ConfigChange::StallThreshold(threshold) => self.stall.set_threshold(threshold),
+
+            _ => {}
+        }
+    }
+
+    /// Runtime configuration, which can be changed while running
+    pub fn config(&self) -> &RuntimeConfig {
+        &self.config
+    }
+
+    /// Track window created by runtime
+    pub(crate) fn add_window(&self, id: WindowId) {
+        self.windows.lock().insert(id);
+        self.milestones.window_created(id, self.now());
+    }
+
+    pub(crate) fn milestones(&self) -> &Milestones {
+        &self.milestones
+    }
+
+    /// Stop tracking destroyed window, returns `true` if it was the last window
+    pub(super) fn remove_window(&self, id: WindowId) -> bool {
+        self.window_states.lock().remove(&id);
+        let mut windows = self.windows.lock();
+
+        windows.remove(&id) && windows.is_empty()
+    }
+
+    /// Check if the window is created by runtime and not destroyed yet
+    pub(crate) fn has_window(&self, id: WindowId) -> bool {
+        self.windows.lock().contains(&id)
+    }
+
+    pub(super) fn has_windows(&self) -> bool {
+        !self.windows.lock().is_empty()
+    }
+
+    /// Check if every windows created by runtime are occluded
+    pub(super) fn all_occluded(&self) -> bool {
+        let windows = self.windows.lock();
+        let states = self.window_states.lock();
+
+        !windows.is_empty()
+            && windows
+                .iter()
+                .all(|id| states.get(id).is_some_and(|state| state.occluded))
+    }
+
+    /// Check if the window has input focus, last reported by [`WindowEvent::Focused`]
+    pub fn is_focused(&self, id: WindowId) -> bool {
+        self.window_states
+            .lock()
+            .get(&id)
+            .is_some_and(|state| state.focused)
+    }
+
+    /// Check if the window is hidden from view, last reported by [`WindowEvent::Occluded`]
+    pub fn is_occluded(&self, id: WindowId) -> bool {
+        self.window_states
+            .lock()
+            .get(&id)
+            .is_some_and(|state| state.occluded)
+    }
+
+    /// Latest inner size and scale factor of the window created with [`build_window`]
+    ///
+    /// Returns [`None`] if window is not created by runtime or destroyed.
+    ///
+    /// [`build_window`]: crate::build_window
+    pub fn metrics(&self, id: WindowId) -> Option<WindowMetrics> {
+        self.window_states.lock().get(&id)?.metrics
+    }
+
+    pub(crate) fn set_metrics(&self, id: WindowId, metrics: WindowMetrics) {
+        self.window_states.lock().entry(id).or_default().metrics = Some(metrics);
+    }
+
+    /// Update cached metrics of the window, if it is tracked
+    pub(super) fn update_metrics(&self, id: WindowId, f: impl FnOnce(&mut WindowMetrics)) {
+        if let Some(metrics) = self
+            .window_states
+            .lock()
+            .get_mut(&id)
+            .and_then(|state| state.metrics.as_mut())
+        {
+            f(metrics);
+        }
+    }
+
+    /// Update focus state of the window, returns `true` if it is changed
+    pub(super) fn set_focused(&self, id: WindowId, focused: bool) -> bool {
+        let mut states = self.window_states.lock();
+        let state = states.entry(id).or_default();
+
+        std::mem::replace(&mut state.focused, focused) != focused
+    }
+
+    /// Schedule redraw of the window at most once per `interval`
+    pub(super) fn schedule_redraw(
+        &self,
+        id: WindowId,
+        now: Instant,
+        interval: Duration,
+    ) -> RedrawSchedule {
+        let mut states = self.window_states.lock();
+        let state = states.entry(id).or_default();
+
+        match state.last_redraw {
+            Some(last) if now < last + interval => {
+                if std::mem::replace(&mut state.redraw_deferred, true) {
+                    RedrawSchedule::Pending
+                } else {
+                    RedrawSchedule::Deferred(last + interval)
+                }
+            }
+
+            _ => {
+                state.last_redraw = Some(now);
+                state.redraw_deferred = false;
+                RedrawSchedule::Now
+            }
+        }
+    }
+
+    /// Update occlusion state of the window, returns `true` if it is changed
+    pub(super) fn set_occluded(&self, id: WindowId, occluded: bool) -> bool {
+        let mut states = self.window_states.lock();
+        let state = states.entry(id).or_default();
+
+        std::mem::replace(&mut state.occluded, occluded) != occluded
+    }
+
+    /// Start intercepting close requests of the window
+    pub(crate) fn guard_close(&self, id: WindowId) {
+        self.close_guards.lock().insert(id, false);
+    }
+
+    pub(crate) fn unguard_close(&self, id: WindowId) {
+        self.close_guards.lock().remove(&id);
+    }
+
+    /// Let next close request of guarded window through
+    pub(crate) fn allow_close(&self, id: WindowId) {
+        if let Some(allowed) = self.close_guards.lock().get_mut(&id) {
+            *allowed = true;
+        }
+    }
+
+    /// Check if close request of the window should be intercepted
+    pub(super) fn intercept_close(&self, id: WindowId) -> bool {
+        match self.close_guards.lock().get_mut(&id) {
+            Some(allowed) => !std::mem::take(allowed),
+            None => false,
+        }
+    }
+
+    /// Check if application is between [`resumed`] and [`suspended`] events
+    ///
+    /// [`resumed`]: crate::resumed
+    /// [`suspended`]: crate::suspended
+    pub fn is_resumed(&self) -> bool {
+        self.resumed.load(Ordering::Acquire)
+    }
+
+    pub(super) fn set_resumed(&self, resumed: bool) {
+        if resumed {
+            self.milestones.resumed(self.now());
+            self.resume_generation.fetch_add(1, Ordering::AcqRel);
+
+            let mut token = self.suspend_token.lock();
+            if token.is_cancelled() {
+                *token = self.exit_token.child();
+            }
+        } else {
+            self.suspend_token.lock().cancel();
+        }
+
+        self.resumed.store(resumed, Ordering::Release);
+    }
+
+    /// Token cancelled once event loop is exiting, before [`exiting`](crate::exiting) event
+    ///
+    /// Use child tokens of it to stop tasks on exit.
+    pub fn exit_token(&self) -> &CancellationToken {
+        &self.exit_token
+    }
+
+    /// Token cancelled once application is suspended, before [`suspended`](crate::suspended) event
+    ///
+    /// New token is created on every resume. Returned token is cancelled already if application is suspended.
+    pub fn suspend_token(&self) -> CancellationToken {
+        let token = self.suspend_token.lock().clone();
+
+        if !self.is_resumed() {
+            token.cancel();
+        }
+
+        token
+    }
+
+    /// Number of [`resumed`](crate::resumed) events so far, for detecting suspension in between
+    pub(crate) fn resume_generation(&self) -> u64 {
+        self.resume_generation.load(Ordering::Acquire)
+    }
+
+    /// When device events are captured while there are [`device`] listeners. Default is [`DeviceEvents::WhenFocused`].
+    ///
+    /// Device events are never captured if there is no listener.
+    ///
+    /// [`device`]: crate::device
+    #[cfg(feature = "device-events")]
+    pub fn device_event_filter(&self) -> DeviceEvents {
+        *self.device_filter.lock()
+    }
+
+    #[cfg(feature = "device-events")]
+    pub fn set_device_event_filter(&self, filter: DeviceEvents) {
+        *self.device_filter.lock() = filter;
+        self.wake();
+    }
+
+    /// Wake event loop
+    pub(crate) fn wake(&self) {
+        self.proxy.wake();
+    }
+
+    /// Check if current thread is event loop thread
+    pub fn is_runtime_thread(&self) -> bool {
+        thread::current().id() == self.thread_id
+    }
+
+    /// Panic with name of `api` and current thread if it is not event loop thread
+    #[track_caller]
+    pub(crate) fn assert_runtime_thread(&self, api: &str) {
+        if !self.is_runtime_thread() {
+            let thread = thread::current();
+
+            panic!(
+                "Cannot call {api} outside of runtime thread, called on thread `{}`",
+                thread.name().unwrap_or("<unnamed>")
+            );
+        }
+    }
+
+    /// Exit event loop
+    ///
+    /// See [`ExecutorHandle::exit_with`]
     pub async fn exit(&self) -> ! {
-        self.proxy.lock().send_event(ExecutorEvent::Exit).unwrap();
-        futures_lite::future::pending().await
+        self.exit_with(0).await
+    }
+
+    /// Exit event loop with exit code
+    ///
+    /// Exit can be cancelled by [`exit_requested`] listeners, then returned future never completes.
+    /// Use [`ExecutorHandle::request_exit`] to handle cancellation.
+    ///
+    /// [`exit_requested`]: crate::exit_requested
+    pub async fn exit_with(&self, code: i32) -> ! {
+        let _ = self.request_exit(code).await;
+        future::pending().await
+    }
+
+    /// Request exiting event loop with exit code, returns if it is cancelled
+    ///
+    /// [`exit_requested`] is emitted first, and cleanups added by its listeners run before event loop exits.
+    ///
+    /// [`exit_requested`]: crate::exit_requested
+    pub async fn request_exit(&self, code: i32) -> ExitCancelled {
+        let mut request = ExitRequest::new(code);
+        emit!(exit_requested(), &mut request);
+
+        let Some(cleanup) = request.into_cleanup() else {
+            return ExitCancelled;
+        };
+
+        for cleanup in cleanup {
+            cleanup.await;
+        }
+
+        self.exit_code.store(code, Ordering::Relaxed);
+
+        // Event loop is exiting already if it fails
+        let _ = self.proxy.send_event(ExecutorEvent::Exit);
+        future::pending().await
+    }
+
+    /// Exit code of last accepted exit request, `0` if there was none
+    ///
+    /// Can be read after event loop exits, for passing it to [`std::process::exit`].
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code.load(Ordering::Relaxed)
+    }
+
+    fn timer_slack(&self) -> Duration {
+        let focused = self
+            .window_states
+            .lock()
+            .values()
+            .any(|state| state.focused);
+
+        self.config.effective_timer_slack(focused)
     }
 
     /// Create Future waiting for given duration.
-    pub fn wait(&self, delay: Duration) -> TimerFuture {
-        let fut = self.timer.delay(delay);
+    pub fn wait(&self, delay: Duration) -> TimerFuture<'_> {
+        let fut = self.timer.delay(delay, self.timer_slack());
 
-        self.proxy.lock().send_event(ExecutorEvent::Wake).unwrap();
+        self.wake();
 
         fut
     }
 
-    /// Create Future waiting for given timestamp
-    pub fn wait_deadline(&self, timestamp: u64) -> TimerFuture {
-        let fut = self.timer.deadline(timestamp);
+    /// Create Future waiting until given instant
+    pub fn wait_until(&self, deadline: Instant) -> TimerFuture<'_> {
+        let fut = self.timer.deadline(deadline, self.timer_slack());
 
-        self.proxy.lock().send_event(ExecutorEvent::Wake).unwrap();
+        self.wake();
 
         fut
     }
 
+    /// Create Future waiting for given timestamp, in milliseconds since UNIX epoch
+    pub fn wait_deadline(&self, timestamp: u64) -> TimerFuture<'_> {
+        let delay = timestamp.saturating_sub(instant::now() as u64);
+
+        self.wait(Duration::from_millis(delay))
+    }
+
+    /// Create Future waiting for given duration, failing with [`RuntimeError::EventLoopClosed`] once runtime exits
+    ///
+    /// Unlike [`ExecutorHandle::wait`] it never hangs after runtime exits, so it can be awaited on other executors.
+    pub async fn try_wait(&self, delay: Duration) -> Result<(), RuntimeError> {
+        self.exit_token
+            .run_until_cancelled(self.wait(delay))
+            .await
+            .ok_or(RuntimeError::EventLoopClosed)
+    }
+
+    /// Create Future waiting until given instant, failing with [`RuntimeError::EventLoopClosed`] once runtime exits
+    ///
+    /// See [`ExecutorHandle::try_wait`]
+    pub async fn try_wait_until(&self, deadline: Instant) -> Result<(), RuntimeError> {
+        self.exit_token
+            .run_until_cancelled(self.wait_until(deadline))
+            .await
+            .ok_or(RuntimeError::EventLoopClosed)
+    }
+
+    /// Check if runtime is shut down, so spawned tasks are cancelled and posted closures are dropped
+    ///
+    /// See [`on_after_shutdown`](super::on_after_shutdown)
+    pub fn is_shut_down(&self) -> bool {
+        self.remote.is_closed()
+    }
+
+    /// Current time of runtime clock
+    pub fn now(&self) -> Instant {
+        self.timer.now()
+    }
+
+    /// Current timer statistics
+    pub fn timer_stats(&self) -> TimerStats {
+        self.timer.stats()
+    }
+
     /// Spawn a new task, running on runtime thread
     ///
-    /// Because it can be called on outside of runtime thread, the Future and its output must be [`Send`]
+    /// Because it can be called on outside of runtime thread, the Future and its output must be [`Send`].
+    /// Task spawned after runtime is shut down is cancelled, see [`ExecutorHandle::try_spawn`].
+    #[track_caller]
     pub fn spawn<Fut>(&self, fut: Fut) -> Task<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        self.spawn_with_priority(Priority::Normal, fut)
+    }
+
+    /// Spawn a new task, running on runtime thread
+    ///
+    /// Returns [`RuntimeError::EventLoopClosed`] if runtime is shut down. See [`ExecutorHandle::spawn`]
+    #[track_caller]
+    pub fn try_spawn<Fut>(&self, fut: Fut) -> Result<Task<Fut::Output>, RuntimeError>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        if self.is_shut_down() {
+            return Err(RuntimeError::EventLoopClosed);
+        }
+
+        Ok(self.spawn(fut))
+    }
+
+    /// Spawn a new task with given [`Priority`], running on runtime thread
+    ///
+    /// See [`ExecutorHandle::spawn`]
+    #[track_caller]
+    pub fn spawn_with_priority<Fut>(&self, priority: Priority, fut: Fut) -> Task<Fut::Output>
     where
         Fut: Future + Send + 'static,
         Fut::Output: Send + 'static,
     {
         // SAFETY: Future and its output is both Send and 'static
-        unsafe { self.spawn_unchecked(fut) }
+        unsafe { self.spawn_unchecked_with_priority(priority, fut) }
     }
 
     /// Spawn and run new task, on runtime thread.
     ///
     /// Unlike `ExecutorHandle::spawn` this method check if this method called on runtime's thread and will panic if it didn't.
     /// Therefore the Future and its output does not need to be [`Send`]
+    #[track_caller]
     pub fn spawn_local<Fut>(&self, fut: Fut) -> Task<Fut::Output>
     where
         Fut: Future + 'static,
         Fut::Output: 'static,
     {
-        if thread::current().id() != self.thread_id {
-            panic!("Cannot call spawn_local outside of event loop thread");
+        self.spawn_local_with_priority(Priority::Normal, fut)
+    }
+
+    /// Spawn and run new task with given [`Priority`], on runtime thread.
+    ///
+    /// See [`ExecutorHandle::spawn_local`]
+    #[track_caller]
+    pub fn spawn_local_with_priority<Fut>(&self, priority: Priority, fut: Fut) -> Task<Fut::Output>
+    where
+        Fut: Future + 'static,
+        Fut::Output: 'static,
+    {
+        self.assert_runtime_thread("spawn_local");
+
+        // SAFETY: Future runs on same thread and its output is 'static
+        unsafe { self.spawn_unchecked_with_priority(priority, fut) }
+    }
+
+    /// Spawn and run new task, on runtime thread. Fails if it is not called on runtime thread.
+    ///
+    /// See [`ExecutorHandle::spawn_local`]
+    #[track_caller]
+    pub fn try_spawn_local<Fut>(&self, fut: Fut) -> Result<Task<Fut::Output>, RuntimeError>
+    where
+        Fut: Future + 'static,
+        Fut::Output: 'static,
+    {
+        if !self.is_runtime_thread() {
+            return Err(RuntimeError::NotRuntimeThread);
+        }
+
+        if self.is_shut_down() {
+            return Err(RuntimeError::EventLoopClosed);
         }
 
         // SAFETY: Future runs on same thread and its output is 'static
-        unsafe { self.spawn_unchecked(fut) }
+        Ok(unsafe { self.spawn_unchecked_with_priority(Priority::Normal, fut) })
     }
 
     /// Spawn and run new task, without checking Future and its output's bound.
@@ -95,26 +582,272 @@ impl ExecutorHandle {
     /// If [`Future`] and its output is
     /// 1. not [`Send`]: Must be called on main thread.
     /// 2. non 'static: References to Future must outlive.
+    #[track_caller]
     pub unsafe fn spawn_unchecked<Fut>(&self, fut: Fut) -> Task<Fut::Output>
     where
         Fut: Future,
     {
-        let (runnable, task) = self.spawn_raw_unchecked(fut);
+        self.spawn_unchecked_with_priority(Priority::Normal, fut)
+    }
+
+    /// Spawn and run new task with given [`Priority`], without checking Future and its output's bound.
+    ///
+    /// # Safety
+    /// See [`ExecutorHandle::spawn_unchecked`]
+    #[track_caller]
+    pub unsafe fn spawn_unchecked_with_priority<Fut>(
+        &self,
+        priority: Priority,
+        fut: Fut,
+    ) -> Task<Fut::Output>
+    where
+        Fut: Future,
+    {
+        self.spawn_task_unchecked(priority, None, fut)
+    }
+
+    /// Spawn a new named task, running on runtime thread
+    ///
+    /// Name is shown on [`ExecutorHandle::dump_tasks`]. See [`ExecutorHandle::spawn`]
+    #[track_caller]
+    pub fn spawn_named<Fut>(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        fut: Fut,
+    ) -> Task<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        // SAFETY: Future and its output is both Send and 'static
+        unsafe { self.spawn_task_unchecked(Priority::Normal, Some(name.into()), fut) }
+    }
+
+    /// Spawn and run new named task, on runtime thread.
+    ///
+    /// Name is shown on [`ExecutorHandle::dump_tasks`]. See [`ExecutorHandle::spawn_local`]
+    #[track_caller]
+    pub fn spawn_local_named<Fut>(
+        &self,
+        name: impl Into<Cow<'static, str>>,
+        fut: Fut,
+    ) -> Task<Fut::Output>
+    where
+        Fut: Future + 'static,
+        Fut::Output: 'static,
+    {
+        self.assert_runtime_thread("spawn_local_named");
+
+        // SAFETY: Future runs on same thread and its output is 'static
+        unsafe { self.spawn_task_unchecked(Priority::Normal, Some(name.into()), fut) }
+    }
+
+    /// Spawn a new deadline task, running on runtime thread
+    ///
+    /// Deadline tasks are run before tasks of every [`Priority`], earliest deadline first.
+    /// Task completed after its deadline is reported to [`deadline_missed`](super::deadline_missed) event source.
+    /// See [`ExecutorHandle::spawn`]
+    #[track_caller]
+    pub fn spawn_before<Fut>(&self, deadline: Instant, fut: Fut) -> Task<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        let fut = deadline::track(deadline, Location::caller(), fut);
+
+        // SAFETY: Future and its output is both Send and 'static
+        unsafe { self.spawn_task_unchecked(Lane::Deadline(deadline), None, fut) }
+    }
+
+    /// Spawn and run new deadline task, on runtime thread.
+    ///
+    /// See [`ExecutorHandle::spawn_before`] and [`ExecutorHandle::spawn_local`]
+    #[track_caller]
+    pub fn spawn_local_before<Fut>(&self, deadline: Instant, fut: Fut) -> Task<Fut::Output>
+    where
+        Fut: Future + 'static,
+        Fut::Output: 'static,
+    {
+        self.assert_runtime_thread("spawn_local_before");
+        let fut = deadline::track(deadline, Location::caller(), fut);
+
+        // SAFETY: Future runs on same thread and its output is 'static
+        unsafe { self.spawn_task_unchecked(Lane::Deadline(deadline), None, fut) }
+    }
+
+    /// Snapshot of every live tasks, including the main task
+    pub fn dump_tasks(&self) -> Vec<TaskSnapshot> {
+        self.tasks.snapshot()
+    }
+
+    /// # Safety
+    /// See [`ExecutorHandle::spawn_unchecked`]
+    #[track_caller]
+    unsafe fn spawn_task_unchecked<Fut>(
+        &self,
+        lane: impl Into<Lane>,
+        name: Option<Cow<'static, str>>,
+        fut: Fut,
+    ) -> Task<Fut::Output>
+    where
+        Fut: Future,
+    {
+        let (runnable, task, _) = self.spawn_raw_unchecked(lane, name, fut);
         runnable.schedule();
 
         task
     }
 
+    pub(super) fn proxy(&self) -> SharedProxy {
+        self.proxy.clone()
+    }
+
+    /// Run closure on runtime thread in next event loop iteration, before control flow is updated
+    ///
+    /// Cheaper than spawning a task, for callbacks of other threads which only need to touch runtime thread.
+    /// Closures are run in order they are posted. Closures posted while running them are run in next iteration.
+    pub fn post(&self, f: impl FnOnce() + Send + 'static) {
+        if self.is_shut_down() {
+            shutdown::report(AfterShutdown::Posted);
+            return;
+        }
+
+        // Queue is never closed
+        let _ = self.posted.push(Box::new(f));
+        self.proxy.wake();
+    }
+
+    /// Run closures posted so far, returns `true` if any was run
+    pub(super) fn run_posted(&self) -> bool {
+        let count = self.posted.len();
+
+        for f in self.posted.try_iter().take(count) {
+            f();
+        }
+
+        count > 0
+    }
+
+    /// Drop closures not run yet
+    pub(super) fn clear_posted(&self) {
+        self.posted.try_iter().for_each(drop);
+    }
+
+    /// Run hook on runtime thread during teardown, after pending tasks are dropped and before main task is dropped
+    ///
+    /// Hooks run in order they are registered. Tasks spawned in hooks are cancelled, as runtime is shut down already.
+    /// Can be called on any thread.
+    pub fn on_exit(&self, hook: impl FnOnce() + Send + 'static) {
+        let _ = self.exit_hooks.push(Box::new(hook));
+    }
+
+    pub(super) fn run_exit_hooks(&self) {
+        for hook in self.exit_hooks.try_iter() {
+            hook();
+        }
+    }
+
+    /// Dispatch synthetic [`WindowEvent`] on runtime thread, as if it was sent by event loop
+    pub fn inject_window_event(&self, window_id: WindowId, event: WindowEvent) {
+        if self
+            .proxy
+            .send_event(ExecutorEvent::WindowEvent(window_id, event))
+            .is_err()
+        {
+            shutdown::report(AfterShutdown::EventDropped);
+        }
+    }
+
+    /// Dispatch synthetic [`DeviceEvent`] on runtime thread, as if it was sent by event loop
+    #[cfg(feature = "device-events")]
+    pub fn inject_device_event(&self, device_id: DeviceId, event: DeviceEvent) {
+        if self
+            .proxy
+            .send_event(ExecutorEvent::DeviceEvent(device_id, event))
+            .is_err()
+        {
+            shutdown::report(AfterShutdown::EventDropped);
+        }
+    }
+
     /// # Safety
     /// See [`ExecutorHandle::spawn_unchecked`]
-    pub(super) unsafe fn spawn_raw_unchecked<Fut>(&self, fut: Fut) -> (Runnable, Task<Fut::Output>)
+    #[track_caller]
+    pub(super) unsafe fn spawn_raw_unchecked<Fut>(
+        &self,
+        lane: impl Into<Lane>,
+        name: Option<Cow<'static, str>>,
+        fut: Fut,
+    ) -> (Runnable, Task<Fut::Output>, u64)
     where
         Fut: Future,
     {
-        let proxy = self.proxy.lock().clone();
+        let lane = lane.into();
+        let thread_id = self.thread_id;
+        let proxy = self.proxy();
+        let remote = self.remote.clone();
+        let tasks = self.tasks.clone();
+
+        let entry = self.tasks.register(name, Location::caller());
+        let id = entry.id();
+        let fut = Tracked::new(fut, entry.clone(), self.tasks.clone());
+
+        let (runnable, task) = async_task::spawn_unchecked(fut, move |runnable| {
+            if remote.is_closed() {
+                if !tasks.is_cancelling() {
+                    shutdown::report(AfterShutdown::TaskScheduled(entry.snapshot()));
+                }
+
+                // Task is cancelled by dropping its runnable.
+                // Runnable of local task cannot be dropped on other threads, so it is leaked there.
+                if thread::current().id() == thread_id {
+                    drop(runnable);
+                } else {
+                    mem::forget(runnable);
+                }
+
+                return;
+            }
+
+            entry.scheduled();
+
+            // Woken on runtime thread, skip proxy round trip
+            if thread::current().id() == thread_id {
+                queue::push(lane, runnable);
+            } else if remote.push(lane, runnable) {
+                proxy.wake();
+            }
+        });
+
+        (runnable, task, id)
+    }
 
-        async_task::spawn_unchecked(fut, move |runnable| {
-            let _ = proxy.send_event(ExecutorEvent::PollTask(runnable));
-        })
+    /// Cancel every task except given one. Runtime must be shut down.
+    pub(super) fn cancel_tasks_except(&self, id: u64) {
+        self.tasks.cancel_except(id);
     }
 }
+
+/// Last reported state of a window
+#[derive(Debug, Clone, Copy, Default)]
+struct WindowState {
+    focused: bool,
+    occluded: bool,
+    metrics: Option<WindowMetrics>,
+
+    /// Last redraw dispatched with limited rate
+    last_redraw: Option<Instant>,
+    redraw_deferred: bool,
+}
+
+/// When redraw of a window should be dispatched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RedrawSchedule {
+    Now,
+
+    /// Deferred until given instant
+    Deferred(Instant),
+
+    /// Deferred redraw is pending already
+    Pending,
+}
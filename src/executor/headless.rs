@@ -0,0 +1,106 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Headless executor backends running without winit event loop
+
+use std::sync::mpsc::{self, RecvTimeoutError, TryRecvError};
+
+use futures_lite::Future;
+use winit::event::{Event, StartCause};
+
+use crate::{config::RuntimeConfig, timer::UpdateState};
+
+use super::{event::ExecutorEvent, init_handle, proxy::EventProxy, Executor};
+
+/// Backend of headless runtime, replacing winit event loop with a plain waker loop driven by channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Backend {
+    /// Fake event loop for tests, with a surface always available
+    Test,
+
+    /// Executor and timer only, for command line tools sharing code with ui
+    Timers,
+}
+
+/// Run executor on a fake event loop driven by channel
+pub(crate) fn run(backend: Backend, config: RuntimeConfig, main: impl Future<Output = ()>) {
+    let (sender, receiver) = mpsc::channel();
+
+    let handle = init_handle(EventProxy::Headless(sender), config);
+
+    // SAFETY: Executor lives on this function, closure does not need to be Send and task and references to Future outlive the loop
+    let (mut executor, runnable) = unsafe { Executor::new(handle, main) };
+    runnable.run();
+
+    // Only initial iteration is reported on headless mode
+    executor.dispatch(Event::NewEvents(StartCause::Init));
+
+    // There is always a surface on test backend, and never on timer only backend
+    if backend == Backend::Test {
+        executor.dispatch(Event::Resumed);
+    }
+    executor.run_local();
+
+    loop {
+        if executor.flush_motion() | handle.run_posted() {
+            executor.run_local();
+        }
+
+        let mut state = executor.update_timer();
+        if executor.emit_idle(state) {
+            executor.run_local();
+            state = executor.update_timer();
+        }
+
+        if executor.has_deferred() {
+            // Deferred tasks are pending, do not block
+            state = UpdateState::Triggered;
+        }
+        executor.reset_budget();
+
+        #[cfg(feature = "profiling")]
+        profiling::finish_frame!();
+
+        let event = match state {
+            UpdateState::None => match receiver.recv() {
+                Ok(event) => Some(event),
+                Err(_) => break,
+            },
+
+            UpdateState::Triggered => match receiver.try_recv() {
+                Ok(event) => Some(event),
+                Err(TryRecvError::Empty) => None,
+                Err(TryRecvError::Disconnected) => break,
+            },
+
+            UpdateState::WaitUntil(deadline) => {
+                match receiver.recv_timeout(deadline.saturating_duration_since(handle.timer.now()))
+                {
+                    Ok(event) => Some(event),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        };
+
+        match event {
+            Some(Event::UserEvent(ExecutorEvent::Exit)) => break,
+
+            Some(event) => {
+                // Each received event is an iteration on headless mode
+                handle.proxy().woken();
+                executor.dispatch(event);
+            }
+
+            None => {}
+        }
+
+        executor.run_local();
+    }
+
+    executor.dispatch(Event::LoopExiting);
+    executor.run_local();
+}
@@ -0,0 +1,247 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use instant::{Duration, Instant};
+use parking_lot::{const_mutex, Mutex};
+use winit::event::{Event, WindowEvent};
+
+use crate::{spawn_ui_task, task::Task, timer};
+
+use super::{event::ExecutorEvent, is_input};
+
+/// Number of latest samples kept per category for percentiles
+const SAMPLES: usize = 1024;
+
+/// Category of dispatched events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EventCategory {
+    /// Keyboard, mouse and touch input of windows
+    Input,
+    Redraw,
+
+    /// Other window events
+    Window,
+    Device,
+
+    /// Resume, suspend, exit and memory warning
+    Lifecycle,
+
+    /// Runtime wakes and other runtime events
+    User,
+
+    /// Running woken tasks, measured per event loop iteration
+    Tasks,
+}
+
+impl EventCategory {
+    pub const ALL: [Self; 7] = [
+        Self::Input,
+        Self::Redraw,
+        Self::Window,
+        Self::Device,
+        Self::Lifecycle,
+        Self::User,
+        Self::Tasks,
+    ];
+
+    pub(super) fn of(event: &Event<ExecutorEvent>) -> Option<Self> {
+        match event {
+            Event::WindowEvent { event, .. }
+            | Event::UserEvent(ExecutorEvent::WindowEvent(_, event)) => {
+                Some(Self::of_window(event))
+            }
+
+            Event::DeviceEvent { .. } | Event::UserEvent(ExecutorEvent::DeviceEvent(..)) => {
+                Some(Self::Device)
+            }
+
+            Event::Resumed | Event::Suspended | Event::LoopExiting | Event::MemoryWarning => {
+                Some(Self::Lifecycle)
+            }
+
+            Event::UserEvent(_) => Some(Self::User),
+
+            Event::NewEvents(_) | Event::AboutToWait => None,
+        }
+    }
+
+    fn of_window(event: &WindowEvent) -> Self {
+        match event {
+            WindowEvent::RedrawRequested => Self::Redraw,
+            event if is_input(event) => Self::Input,
+            _ => Self::Window,
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Input => "input",
+            Self::Redraw => "redraw",
+            Self::Window => "window",
+            Self::Device => "device",
+            Self::Lifecycle => "lifecycle",
+            Self::User => "user",
+            Self::Tasks => "tasks",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Samples {
+    latest: VecDeque<Duration>,
+    count: u64,
+    max: Duration,
+}
+
+impl Samples {
+    fn push(&mut self, duration: Duration) {
+        if self.latest.len() >= SAMPLES {
+            self.latest.pop_front();
+        }
+        self.latest.push_back(duration);
+
+        self.count += 1;
+        self.max = self.max.max(duration);
+    }
+
+    fn stats(&self) -> LatencyStats {
+        let mut sorted: Vec<_> = self.latest.iter().copied().collect();
+        sorted.sort_unstable();
+
+        let percentile = |p: usize| match sorted.len() {
+            0 => Duration::ZERO,
+            len => sorted[((len - 1) * p) / 100],
+        };
+
+        LatencyStats {
+            count: self.count,
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: self.max,
+        }
+    }
+}
+
+static RECORDS: Mutex<Option<[Samples; 7]>> = const_mutex(None);
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+pub(super) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Start or stop collecting, dropping collected samples when stopped
+pub(super) fn set_enabled(enabled: bool) {
+    if !ENABLED.swap(enabled, Ordering::Relaxed) || enabled {
+        return;
+    }
+
+    *RECORDS.lock() = None;
+}
+
+/// Record time taken from event arrival until its listeners finish
+pub(super) fn record(category: EventCategory, start: Instant) {
+    let elapsed = start.elapsed();
+
+    RECORDS.lock().get_or_insert_with(Default::default)[category.index()].push(elapsed);
+}
+
+/// Dispatch latency statistics of events in a category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LatencyStats {
+    /// Number of events recorded since runtime started
+    pub count: u64,
+
+    /// Percentiles of latest samples
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+
+    /// Maximum since runtime started
+    pub max: Duration,
+}
+
+/// Snapshot of event dispatch latencies, created with [`metrics`]
+#[derive(Debug, Clone, Default)]
+pub struct EventMetrics {
+    stats: [LatencyStats; 7],
+}
+
+impl EventMetrics {
+    pub fn get(&self, category: EventCategory) -> LatencyStats {
+        self.stats[category.index()]
+    }
+
+    /// Iterate stats of categories with recorded events
+    pub fn iter(&self) -> impl Iterator<Item = (EventCategory, LatencyStats)> + '_ {
+        EventCategory::ALL
+            .into_iter()
+            .map(|category| (category, self.get(category)))
+            .filter(|(_, stats)| stats.count > 0)
+    }
+}
+
+/// Formats as single log line, like `input n=120 p50=12µs p90=40µs p99=1.2ms max=3ms; ...`
+impl Display for EventMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (category, stats)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+
+            write!(
+                f,
+                "{} n={} p50={:?} p90={:?} p99={:?} max={:?}",
+                category.name(),
+                stats.count,
+                stats.p50,
+                stats.p90,
+                stats.p99,
+                stats.max
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Take snapshot of event dispatch latencies
+///
+/// Latency of an event is measured from when event loop hands it to runtime until its listeners finish.
+pub fn metrics() -> EventMetrics {
+    let records = RECORDS.lock();
+
+    let mut metrics = EventMetrics::default();
+    if let Some(records) = records.as_ref() {
+        for category in EventCategory::ALL {
+            metrics.stats[category.index()] = records[category.index()].stats();
+        }
+    }
+
+    metrics
+}
+
+/// Print [`metrics`] to stderr every `interval`, until returned task is dropped
+pub fn log_metrics(interval: Duration) -> Task<()> {
+    spawn_ui_task(async move {
+        loop {
+            timer::wait(interval).await;
+            eprintln!("[winit-runtime] {}", metrics());
+        }
+    })
+}
@@ -6,35 +6,202 @@
 
 //! Implementation of winit Executor
 
+mod app;
+mod builder;
+mod deadline;
+mod error;
 pub mod event;
+mod exit;
 pub mod handle;
+pub(crate) mod headless;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod progress;
+mod proxy;
+mod queue;
+mod shutdown;
+mod stall;
+mod tasks;
 
-use std::sync::OnceLock;
+use std::{
+    ptr,
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+};
 
-use async_task::Task;
-use event_source::emit;
+use async_task::{Runnable, Task};
 use futures_lite::Future;
-use instant::Duration;
+use instant::{Duration, Instant};
 use scoped_tls_hkt::scoped_thread_local;
 use winit::{
     error::EventLoopError,
-    event::Event,
-    event_loop::{ControlFlow, EventLoopBuilder, EventLoopWindowTarget},
+    event::{Event, WindowEvent},
+    event_loop::{ActiveEventLoop, ControlFlow, DeviceEvents, EventLoopBuilder},
+    window::WindowId,
+};
+
+#[cfg(not(any(target_arch = "wasm32", target_os = "ios")))]
+use winit::event_loop::EventLoop;
+
+use crate::{
+    config::{DispatchOrder, RuntimeConfig},
+    emit,
+    event::EventSource,
+    exiting, input, memory_warning, new_events, raw_event, record, resumed, scale_factor,
+    suspended,
+    timer::UpdateState,
+    window,
 };
 
-use crate::{device, resumed, suspended, timer::UpdateState, window};
+use self::{
+    event::ExecutorEvent,
+    handle::{ExecutorHandle, RedrawSchedule},
+    proxy::EventProxy,
+    queue::Budget,
+};
+
+pub use builder::RuntimeBuilder;
+pub use deadline::{deadline_missed, DeadlineMiss};
+pub use error::{RunError, RuntimeError};
+use exit::Watchdog;
+pub use exit::{ExitCancelled, ExitRequest};
+#[cfg(feature = "metrics")]
+pub use metrics::{log_metrics, metrics, EventCategory, EventMetrics, LatencyStats};
+pub use progress::{spawn_with_progress, Progress, ProgressStream};
+pub use queue::Priority;
+pub use shutdown::{on_after_shutdown, AfterShutdown};
+pub use stall::stalled;
+pub use tasks::{TaskSnapshot, TaskState};
+
+pub type EventLoopTarget = ActiveEventLoop;
+
+/// Handle of running or last runtime. Handles are leaked, so tasks of previous runtimes can keep using them.
+static HANDLE: AtomicPtr<ExecutorHandle> = AtomicPtr::new(ptr::null_mut());
 
-use self::{event::ExecutorEvent, handle::ExecutorHandle};
+/// Runtime is running on any thread
+static RUNNING: AtomicBool = AtomicBool::new(false);
 
-pub type EventLoopTarget = EventLoopWindowTarget<ExecutorEvent>;
+fn current_handle() -> Option<&'static ExecutorHandle> {
+    // SAFETY: Handle is leaked, so it is never freed once stored
+    unsafe { HANDLE.load(Ordering::Acquire).as_ref() }
+}
+
+/// Yield current task, letting pending events and other scheduled tasks run first
+///
+/// Task is rescheduled on runtime thread's queue and polled again on next event loop iteration.
+pub async fn yield_now() {
+    futures_lite::future::yield_now().await
+}
+
+static IDLE: crate::EventSource!(()) = EventSource::new();
+
+/// Wait until event loop becomes idle
+///
+/// Event loop is idle when it is about to sleep with no pending task and no imminent timer.
+pub async fn idle() {
+    IDLE.once(|_, _| Some(())).await;
+}
 
-static HANDLE: OnceLock<ExecutorHandle> = OnceLock::new();
+/// Call `f` every time event loop becomes idle, for housekeeping work like cache trimming or autosave
+///
+/// See [`idle`]
+pub async fn on_idle(mut f: impl FnMut() + Send) {
+    IDLE.on(|_, _| f()).await
+}
 
 /// Get current [`ExecutorHandle`]
 ///
-/// There can be only one [`ExecutorHandle`] and will panic if executor did not start.
+/// Returns handle of last runtime after it exits, until another one starts. Panics if executor did not start.
 pub fn executor_handle() -> &'static ExecutorHandle {
-    HANDLE.get().expect("Executor is not started")
+    current_handle().expect("Executor is not started")
+}
+
+/// Check if current thread is runtime thread, returns `false` if executor did not start
+///
+/// Useful for choosing between [`spawn_ui_task`](crate::spawn_ui_task) and [`spawn_local_ui_task`](crate::spawn_local_ui_task).
+pub fn is_runtime_thread() -> bool {
+    current_handle().is_some_and(ExecutorHandle::is_runtime_thread)
+}
+
+/// Panic if current thread is not runtime thread, for validating callbacks which must run on it
+///
+/// Panic message contains caller location and name of current thread.
+#[track_caller]
+pub fn assert_runtime_thread() {
+    executor_handle().assert_runtime_thread("this function");
+}
+
+/// Get current [`ExecutorHandle`], without panicking if executor did not start
+pub fn try_executor_handle() -> Result<&'static ExecutorHandle, RuntimeError> {
+    current_handle().ok_or(RuntimeError::NotStarted)
+}
+
+fn init_handle(proxy: EventProxy, config: RuntimeConfig) -> &'static ExecutorHandle {
+    if RUNNING.swap(true, Ordering::AcqRel) {
+        panic!("Executor is already running");
+    }
+
+    let handle: &'static ExecutorHandle = Box::leak(Box::new(ExecutorHandle::new(proxy, config)));
+    HANDLE.store(handle as *const _ as *mut _, Ordering::Release);
+
+    handle
+}
+
+/// Snapshot of every live tasks
+///
+/// See [`ExecutorHandle::dump_tasks`]
+pub fn dump_tasks() -> Vec<TaskSnapshot> {
+    executor_handle().dump_tasks()
+}
+
+/// Run hook during teardown after event loop exits
+///
+/// See [`ExecutorHandle::on_exit`]
+pub fn on_exit(hook: impl FnOnce() + Send + 'static) {
+    executor_handle().on_exit(hook)
+}
+
+/// Run closure on runtime thread in next event loop iteration
+///
+/// See [`ExecutorHandle::post`]
+pub fn post(f: impl FnOnce() + Send + 'static) {
+    executor_handle().post(f)
+}
+
+/// Dispatch synthetic [`WindowEvent`] on runtime thread
+///
+/// See [`ExecutorHandle::inject_window_event`]
+pub fn inject_window_event(window_id: WindowId, event: WindowEvent) {
+    executor_handle().inject_window_event(window_id, event)
+}
+
+/// Dispatch synthetic [`DeviceEvent`](winit::event::DeviceEvent) on runtime thread
+///
+/// See [`ExecutorHandle::inject_device_event`]
+#[cfg(feature = "device-events")]
+pub fn inject_device_event(device_id: winit::event::DeviceId, event: winit::event::DeviceEvent) {
+    executor_handle().inject_device_event(device_id, event)
+}
+
+/// Run every scheduled tasks and expired timers until none are left, without waiting for event loop
+///
+/// Meant for deterministic tests with [`MockClock`](crate::test::MockClock), called from a task on runtime thread.
+/// Pending events are not dispatched. Never returns if a task keeps rescheduling itself.
+#[cfg(feature = "test-util")]
+pub fn test_tick() {
+    let handle = executor_handle();
+    handle.assert_runtime_thread("test_tick");
+
+    let mut budget = Budget::new(Default::default());
+    loop {
+        handle.remote.drain();
+        let triggered = matches!(handle.timer.update_next(), UpdateState::Triggered);
+
+        if !triggered && !queue::has_pending() {
+            break;
+        }
+
+        queue::run(&mut budget);
+    }
 }
 
 scoped_thread_local!(static EL_TARGET: EventLoopTarget);
@@ -46,87 +213,657 @@ pub fn with_eventloop_target<R>(func: impl FnOnce(&EventLoopTarget) -> R) -> R {
     EL_TARGET.with(func)
 }
 
+/// Run closure using current [`EventLoopTarget`], failing if it called on outside of runtime thread
+///
+/// Event loop target is not available on headless runtime either.
+pub fn try_with_eventloop_target<R>(
+    func: impl FnOnce(&EventLoopTarget) -> R,
+) -> Result<R, RuntimeError> {
+    if EL_TARGET.is_set() {
+        Ok(EL_TARGET.with(func))
+    } else {
+        Err(RuntimeError::NotRuntimeThread)
+    }
+}
+
 #[derive(Debug)]
 struct Executor {
-    _main: Task<()>,
+    main: Option<Task<()>>,
+    main_id: u64,
     handle: &'static ExecutorHandle,
+    budget: Budget,
+    device_events: Option<DeviceEvents>,
+
+    /// Raw mouse motion summed since last [`Executor::flush_motion`]
+    #[cfg(feature = "device-events")]
+    motion: Option<(f64, f64)>,
 }
 
 impl Executor {
+    /// Create executor and spawn main task
+    ///
+    /// # Safety
+    /// Executor and returned [`Runnable`] must be used on current thread and main Future must outlive Executor
+    unsafe fn new(
+        handle: &'static ExecutorHandle,
+        main: impl Future<Output = ()>,
+    ) -> (Self, Runnable) {
+        let proxy = handle.proxy();
+        handle.apply_settings();
+
+        // Start capturing device events once first listener is registered
+        #[cfg(feature = "device-events")]
+        {
+            let wake_on_first = |proxy: proxy::SharedProxy| {
+                move |count| {
+                    if count == 1 {
+                        proxy.wake();
+                    }
+                }
+            };
+
+            crate::device().on_subscribe(wake_on_first(proxy.clone()));
+            crate::raw_motion_frame().on_subscribe(wake_on_first(proxy.clone()));
+        }
+
+        let main = async move {
+            main.await;
+            let _ = proxy.send_event(ExecutorEvent::Exit);
+        };
+
+        let (runnable, task, main_id) =
+            handle.spawn_raw_unchecked(Priority::Normal, Some("main".into()), main);
+
+        (
+            Self {
+                main: Some(task),
+                main_id,
+                handle,
+                budget: Budget::new(handle.config().poll_budget()),
+                device_events: None,
+                #[cfg(feature = "device-events")]
+                motion: None,
+            },
+            runnable,
+        )
+    }
+
     fn on_event(&mut self, event: Event<ExecutorEvent>, target: &EventLoopTarget) {
-        EL_TARGET.set(target, move || match event {
-            Event::UserEvent(ExecutorEvent::Wake) => {}
+        EL_TARGET.set(target, move || {
+            let _busy = self.handle.stall.enter();
+            let about_to_wait = matches!(event, Event::AboutToWait);
+            let defer_tasks = self.handle.config().dispatch_order() == DispatchOrder::InputFirst
+                && defers_tasks(&event);
+
+            match event {
+                Event::UserEvent(ExecutorEvent::Exit) => target.exit(),
+
+                Event::NewEvents(_) => {
+                    self.handle.proxy().woken();
+                    self.reset_budget();
+                }
+
+                // Platforms can drain user events after NewEvents in same iteration,
+                // so clear pending wake before running woken tasks, or later wakes are coalesced into this one.
+                Event::UserEvent(ExecutorEvent::Wake) => self.handle.proxy().woken(),
+
+                _ => {}
+            }
+
+            self.dispatch(event);
+
+            if about_to_wait {
+                #[cfg(feature = "gilrs")]
+                let gamepad = crate::gamepad::pump();
+
+                self.flush_motion();
+                self.handle.run_posted();
+
+                let state = self.update_timer();
+                self.emit_idle(state);
+
+                #[cfg(feature = "gilrs")]
+                let state = if gamepad { poll_gamepad(state) } else { state };
+
+                let paused = self.handle.config().pauses_occluded() && self.handle.all_occluded();
+
+                if !paused && self.handle.config().continuous(self.handle.has_windows()) {
+                    target.set_control_flow(ControlFlow::Poll);
+                } else if let UpdateState::WaitUntil(deadline) = state {
+                    target.set_control_flow(wait_until(deadline));
+                } else if target.control_flow() == ControlFlow::Poll {
+                    target.set_control_flow(ControlFlow::Wait);
+                }
+            }
+
+            if !defer_tasks {
+                self.run_local();
+            }
+
+            if about_to_wait {
+                // Run deferred tasks on next iteration, after pending events
+                if self.has_deferred() {
+                    target.set_control_flow(ControlFlow::Poll);
+                }
+
+                self.update_device_events(target);
+
+                // Iteration ends once event loop waits
+                #[cfg(feature = "profiling")]
+                profiling::finish_frame!();
+            }
+        });
+    }
+
+    /// Wake tasks of fired timers, returns when event loop should wake next
+    fn update_timer(&self) -> UpdateState {
+        #[cfg(feature = "profiling")]
+        profiling::scope!("winit_runtime::timer");
+
+        self.handle.timer.update_next()
+    }
+
+    /// Emit raw mouse motion summed in this iteration, returns `true` if emitted
+    fn flush_motion(&mut self) -> bool {
+        #[cfg(feature = "device-events")]
+        if let Some(delta) = self.motion.take() {
+            emit!(crate::raw_motion_frame(), delta);
+            return true;
+        }
+
+        false
+    }
 
-            Event::UserEvent(ExecutorEvent::PollTask(runnable)) => {
-                runnable.run();
+    /// Capture device events only if there are listeners
+    fn update_device_events(&mut self, target: &EventLoopTarget) {
+        #[cfg(feature = "device-events")]
+        let device_events = if crate::device().listener_count() > 0
+            || crate::raw_motion_frame().listener_count() > 0
+        {
+            self.handle.device_event_filter()
+        } else {
+            DeviceEvents::Never
+        };
+
+        #[cfg(not(feature = "device-events"))]
+        let device_events = DeviceEvents::Never;
+
+        if self.device_events != Some(device_events) {
+            target.listen_device_events(device_events);
+            self.device_events = Some(device_events);
+        }
+    }
+
+    /// Start new poll budget for event loop iteration
+    fn reset_budget(&mut self) {
+        self.budget = Budget::new(self.handle.config().poll_budget());
+    }
+
+    /// Check if there are tasks deferred to next iteration
+    fn has_deferred(&self) -> bool {
+        queue::has_pending()
+    }
+
+    /// Emit [`idle`] event if there is no pending task and timer, returns `true` if emitted
+    fn emit_idle(&mut self, state: UpdateState) -> bool {
+        /// Timers closer than this are considered imminent
+        const IMMINENT: Duration = Duration::from_millis(1);
+
+        self.handle.remote.drain();
+        if queue::has_pending() {
+            return false;
+        }
+
+        let idle = match state {
+            UpdateState::None => true,
+            UpdateState::Triggered => false,
+            UpdateState::WaitUntil(deadline) => {
+                deadline.saturating_duration_since(Instant::now()) >= IMMINENT
             }
+        };
 
-            Event::UserEvent(ExecutorEvent::Exit) => target.exit(),
+        if idle {
+            emit!(IDLE, ());
+        }
 
+        idle
+    }
+
+    /// Run scheduled tasks within budget
+    ///
+    /// Tasks rescheduled while running are deferred to next iteration, without waking event loop.
+    fn run_local(&mut self) {
+        #[cfg(feature = "profiling")]
+        profiling::scope!("winit_runtime::tasks");
+
+        let _busy = self.handle.stall.enter();
+        self.handle.remote.drain();
+
+        #[cfg(feature = "metrics")]
+        if queue::has_pending() && metrics::is_enabled() {
+            let start = Instant::now();
+            queue::run(&mut self.budget);
+            metrics::record(metrics::EventCategory::Tasks, start);
+            return;
+        }
+
+        queue::run(&mut self.budget);
+    }
+
+    /// Dispatch event to [`raw_event`] and then to tasks and event sources
+    fn dispatch(&mut self, event: Event<ExecutorEvent>) {
+        #[cfg(feature = "profiling")]
+        profiling::scope!("winit_runtime::event");
+
+        let _busy = self.handle.stall.enter();
+
+        #[cfg(feature = "metrics")]
+        let measure = metrics::EventCategory::of(&event)
+            .filter(|_| metrics::is_enabled())
+            .map(|category| (category, Instant::now()));
+
+        // Every source emitted for this event shares its metadata
+        crate::event::with_meta(|_| {
+            emit!(raw_event(), &event);
+            self.demux(event);
+        });
+
+        #[cfg(feature = "metrics")]
+        if let Some((category, start)) = measure {
+            metrics::record(category, start);
+        }
+    }
+
+    /// Check if redraw of the window should be dispatched now, deferring it if redraw rate is limited
+    fn allow_redraw(&self, window_id: WindowId) -> bool {
+        let config = self.handle.config();
+        if config.pauses_occluded() && self.handle.is_occluded(window_id) {
+            return false;
+        }
+
+        let Some(interval) = config.power_profile().background_frame_interval() else {
+            return true;
+        };
+        if self.handle.is_focused(window_id) {
+            return true;
+        }
+
+        match self
+            .handle
+            .schedule_redraw(window_id, self.handle.now(), interval)
+        {
+            RedrawSchedule::Now => true,
+
+            RedrawSchedule::Deferred(deadline) => {
+                let handle = self.handle;
+
+                handle
+                    .spawn(async move {
+                        handle.wait_until(deadline).await;
+                        handle.inject_window_event(window_id, WindowEvent::RedrawRequested);
+                    })
+                    .detach();
+
+                false
+            }
+
+            RedrawSchedule::Pending => false,
+        }
+    }
+
+    /// Dispatch event to tasks and event sources
+    fn demux(&mut self, event: Event<ExecutorEvent>) {
+        match event {
+            Event::UserEvent(ExecutorEvent::WindowEvent(window_id, event)) => {
+                self.demux(Event::WindowEvent { window_id, event });
+            }
+
+            Event::UserEvent(ExecutorEvent::DeviceEvent(device_id, event)) => {
+                self.demux(Event::DeviceEvent { device_id, event });
+            }
+
+            #[cfg(feature = "device-events")]
             Event::DeviceEvent { device_id, event } => {
-                emit!(device(), (device_id, &event));
+                record::record_device_event(&event);
+                emit!(crate::device(), (device_id, &event));
+
+                if let winit::event::DeviceEvent::MouseMotion { delta: (x, y) } = event {
+                    if crate::raw_motion_frame().listener_count() > 0 {
+                        let (sum_x, sum_y) = self.motion.get_or_insert((0.0, 0.0));
+                        *sum_x += x;
+                        *sum_y += y;
+                    }
+                }
             }
 
             Event::WindowEvent {
                 window_id,
                 mut event,
             } => {
+                // Deferred until close is confirmed
+                if let WindowEvent::CloseRequested = event {
+                    if self.handle.intercept_close(window_id) {
+                        emit!(window::close_intercepted(), window_id);
+                        return;
+                    }
+                }
+
+                if matches!(event, WindowEvent::RedrawRequested) && !self.allow_redraw(window_id) {
+                    return;
+                }
+
+                record::record_window_event(window_id, &event);
+                input::process_event(window_id, &event);
+
+                #[cfg(feature = "accesskit")]
+                crate::accesskit::process_event(window_id, &event);
+
+                if let WindowEvent::ScaleFactorChanged {
+                    scale_factor,
+                    ref mut inner_size_writer,
+                } = event
+                {
+                    scale_factor::dispatch(window_id, scale_factor, inner_size_writer);
+                }
+
+                let change = match event {
+                    WindowEvent::Focused(focused) => self
+                        .handle
+                        .set_focused(window_id, focused)
+                        .then_some(StateChange::Focused(focused)),
+
+                    WindowEvent::Occluded(occluded) => self
+                        .handle
+                        .set_occluded(window_id, occluded)
+                        .then_some(StateChange::Occluded(occluded)),
+
+                    WindowEvent::Resized(size) => {
+                        self.handle
+                            .update_metrics(window_id, |metrics| metrics.inner_size = size);
+                        None
+                    }
+
+                    WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                        self.handle.update_metrics(window_id, |metrics| {
+                            metrics.scale_factor = scale_factor
+                        });
+                        None
+                    }
+
+                    _ => None,
+                };
+
                 emit!(window(), (window_id, &mut event));
+                window::dispatch_registered(window_id, &mut event);
+                #[cfg(feature = "touch")]
+                crate::touch::process_event(window_id, &event);
+                input::shortcuts::process_event(window_id, &event);
+
+                if let WindowEvent::RedrawRequested = event {
+                    self.handle
+                        .milestones()
+                        .frame_presented(window_id, self.handle.now());
+                }
+
+                match change {
+                    Some(StateChange::Focused(focused)) => {
+                        emit!(window::focused(), (window_id, focused));
+                    }
+
+                    Some(StateChange::Occluded(occluded)) => {
+                        emit!(window::occluded(), (window_id, occluded));
+
+                        // Redraw requests were withheld while occluded
+                        if !occluded && self.handle.config().pauses_occluded() {
+                            self.handle
+                                .inject_window_event(window_id, WindowEvent::RedrawRequested);
+                        }
+                    }
+
+                    None => {}
+                }
+
+                if let WindowEvent::Destroyed = event {
+                    self.handle.milestones().window_destroyed(window_id);
+
+                    if self.handle.remove_window(window_id) && self.handle.config().exit_on_close()
+                    {
+                        let _ = self.handle.proxy().send_event(ExecutorEvent::Exit);
+                    }
+                }
+            }
+
+            Event::NewEvents(cause) => {
+                emit!(new_events(), cause);
             }
 
             Event::Resumed => {
+                self.handle.set_resumed(true);
                 emit!(resumed(), ());
             }
 
             Event::Suspended => {
+                self.handle.set_resumed(false);
                 emit!(suspended(), ());
             }
 
-            Event::AboutToWait => {
-                if let UpdateState::WaitTimeout(next_delay) = self.handle.timer.update_next() {
-                    target.set_control_flow(ControlFlow::wait_duration(Duration::from_millis(
-                        next_delay.get(),
-                    )));
-                } else if target.control_flow() == ControlFlow::Poll {
-                    target.set_control_flow(ControlFlow::Wait);
-                }
+            Event::LoopExiting => {
+                self.handle.exit_token().cancel();
+                emit!(exiting(), ());
+            }
+
+            Event::MemoryWarning => {
+                emit!(memory_warning(), ());
             }
 
             _ => {}
-        });
+        }
+    }
+
+    /// Report every task still alive except main task
+    fn report_leaked_tasks(&self) {
+        let leaked = self
+            .handle
+            .dump_tasks()
+            .into_iter()
+            .filter(|task| task.id != self.main_id)
+            .collect::<Vec<_>>();
+        if leaked.is_empty() {
+            return;
+        }
+
+        shutdown::report(AfterShutdown::TasksLeaked(leaked));
     }
 }
 
-/// Entrypoint for runtime
-pub fn run(main: impl Future<Output = ()>) -> Result<(), EventLoopError> {
-    let event_loop = EventLoopBuilder::with_user_event().build()?;
+impl Drop for Executor {
+    /// Teardown in defined order. Tasks waiting for exit token or [`exiting`] event already ran once event loop exited.
+    fn drop(&mut self) {
+        self.handle.stall.set_threshold(None);
+
+        let _watchdog = self
+            .handle
+            .config()
+            .teardown_deadline()
+            .map(Watchdog::start);
 
-    let handle = {
-        if HANDLE.set(ExecutorHandle::new(&event_loop)).is_err() {
-            panic!("This cannot be happen");
+        if self.handle.config().report_leaked_tasks() {
+            self.report_leaked_tasks();
         }
 
-        HANDLE.get().unwrap()
-    };
+        // Cancel child tasks first. Drop queued runnables before tasks and references to Futures
+        self.handle.remote.close();
+        self.handle.cancel_tasks_except(self.main_id);
+        self.handle.remote.drain();
+        queue::clear();
+        self.handle.clear_posted();
 
-    let (runnable, task) = {
-        let proxy = event_loop.create_proxy();
+        // Drop state bound to this run, as it can hold tasks, so runtime can be started again
+        window::reset();
+        input::reset();
+        #[cfg(feature = "touch")]
+        crate::touch::reset();
 
-        let main = async move {
-            main.await;
-            let _ = proxy.send_event(ExecutorEvent::Exit);
-        };
+        self.handle.run_exit_hooks();
+        drop(self.main.take());
+
+        RUNNING.store(false, Ordering::Release);
+    }
+}
 
-        // SAFETY: EventLoop created on same function, closure does not need to be Send and task and references to Future outlive event loop
-        unsafe { handle.spawn_raw_unchecked(main) }
-    };
+/// Check if woken tasks can wait until pending input events are dispatched
+///
+/// Tasks woken by these events run on next event, at latest once [`Event::AboutToWait`] is dispatched.
+fn defers_tasks(event: &Event<ExecutorEvent>) -> bool {
+    match event {
+        Event::NewEvents(_) | Event::UserEvent(ExecutorEvent::Wake) | Event::DeviceEvent { .. } => {
+            true
+        }
 
-    let mut executor = Executor {
-        _main: task,
-        handle,
-    };
+        Event::WindowEvent { event, .. } => is_input(event),
+
+        _ => false,
+    }
+}
+
+/// Check if the window event is keyboard, mouse or touch input
+fn is_input(event: &WindowEvent) -> bool {
+    matches!(
+        event,
+        WindowEvent::KeyboardInput { .. }
+            | WindowEvent::ModifiersChanged(_)
+            | WindowEvent::Ime(_)
+            | WindowEvent::CursorMoved { .. }
+            | WindowEvent::CursorEntered { .. }
+            | WindowEvent::CursorLeft { .. }
+            | WindowEvent::MouseWheel { .. }
+            | WindowEvent::MouseInput { .. }
+            | WindowEvent::PinchGesture { .. }
+            | WindowEvent::PanGesture { .. }
+            | WindowEvent::DoubleTapGesture { .. }
+            | WindowEvent::RotationGesture { .. }
+            | WindowEvent::TouchpadPressure { .. }
+            | WindowEvent::AxisMotion { .. }
+            | WindowEvent::Touch(_)
+    )
+}
+
+/// Changed window state, emitted after the window event
+enum StateChange {
+    Focused(bool),
+    Occluded(bool),
+}
 
-    EL_TARGET.set(&event_loop, move || runnable.run());
+/// Wake event loop before next gamepad polling
+#[cfg(feature = "gilrs")]
+fn poll_gamepad(state: UpdateState) -> UpdateState {
+    let next = Instant::now() + crate::gamepad::POLL_INTERVAL;
 
-    event_loop.run(move |event, target| executor.on_event(event, target))
+    match state {
+        UpdateState::WaitUntil(deadline) if deadline < next => state,
+        UpdateState::Triggered => state,
+        _ => UpdateState::WaitUntil(next),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn wait_until(deadline: Instant) -> ControlFlow {
+    ControlFlow::WaitUntil(deadline)
+}
+
+/// winit uses its own [`Instant`] on web
+#[cfg(target_arch = "wasm32")]
+fn wait_until(deadline: Instant) -> ControlFlow {
+    ControlFlow::wait_duration(deadline.saturating_duration_since(Instant::now()))
+}
+
+/// Entrypoint for runtime
+///
+/// Use [`RuntimeBuilder`] to start runtime with initial configuration.
+pub fn run(main: impl Future<Output = ()>) -> Result<(), EventLoopError> {
+    RuntimeBuilder::new().run(main)
+}
+
+/// Entrypoint for runtime, with [`EventLoopBuilder`] customization
+///
+/// Use this to set platform specific attributes (X11/Wayland selection, `with_any_thread`, macOS activation policy) before event loop is built.
+pub fn run_with(
+    builder_config: impl FnOnce(&mut EventLoopBuilder<ExecutorEvent>),
+    main: impl Future<Output = ()>,
+) -> Result<(), EventLoopError> {
+    RuntimeBuilder::new().event_loop(builder_config).run(main)
+}
+
+/// Entrypoint for runtime, with synchronous setup once event loop starts
+///
+/// `setup` is called with [`EventLoopTarget`] on first event loop iteration before any event is dispatched, and returns the main Future.
+/// Tasks spawned in `setup` run right after it.
+/// ```ignore
+/// run_with_setup(|target| {
+///     let monitors = target.available_monitors().count();
+///
+///     async move {
+///         // ...
+///     }
+/// })
+/// ```
+pub fn run_with_setup<Fut>(
+    setup: impl FnOnce(&EventLoopTarget) -> Fut,
+) -> Result<(), EventLoopError>
+where
+    Fut: Future<Output = ()>,
+{
+    RuntimeBuilder::new().run_with_setup(setup)
+}
+
+/// Entrypoint for runtime, returning output of the main Future
+///
+/// Panic of main task is resumed once event loop exits, instead of unwinding through event loop.
+/// ```ignore
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     run_result(async_main())??;
+///     Ok(())
+/// }
+/// ```
+pub fn run_result<T>(main: impl Future<Output = T>) -> Result<T, RunError> {
+    RuntimeBuilder::new().run_result(main)
+}
+
+/// Entrypoint for window-less runtime running only tasks and timers, returning output of the main Future
+///
+/// No OS event loop is created, so it can run where display server is unavailable, like command line tools sharing code with ui.
+/// Event sources and timers work as usual, but windows cannot be created and [`resumed`](crate::resumed) is never emitted.
+/// Like [`run`], only one runtime can run at a time.
+/// ```ignore
+/// fn main() -> Result<(), RunError> {
+///     run_headless_timers(async {
+///         let report = build_report().await;
+///         report.save("report.json").await
+///     })?
+///     .expect("failed to build report");
+///     Ok(())
+/// }
+/// ```
+pub fn run_headless_timers<T>(main: impl Future<Output = T>) -> Result<T, RunError> {
+    RuntimeBuilder::new().run_headless_timers(main)
+}
+
+/// Entrypoint for runtime on existing event loop, which can be started again once it exits
+///
+/// See [`RuntimeBuilder::run_on_demand`]
+#[cfg(not(any(target_arch = "wasm32", target_os = "ios")))]
+pub fn run_on_demand(
+    event_loop: &mut EventLoop<ExecutorEvent>,
+    main: impl Future<Output = ()>,
+) -> Result<(), EventLoopError> {
+    RuntimeBuilder::new().run_on_demand(event_loop, main)
+}
+
+/// Entrypoint for runtime on web
+///
+/// Unlike [`run`], this function returns immediately and event loop runs on browser's event loop.
+/// Because of that the main Future must be `'static`.
+#[cfg(target_arch = "wasm32")]
+pub fn run_web(main: impl Future<Output = ()> + 'static) -> Result<(), EventLoopError> {
+    RuntimeBuilder::new().run_web(main)
 }
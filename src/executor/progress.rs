@@ -0,0 +1,98 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use async_task::Task;
+use futures_lite::{future, ready, Future, Stream};
+
+use crate::{
+    spawn_ui_task,
+    sync::{ui_channel, TryRecvError, UiReceiver, UiSender},
+};
+
+/// Spawn task reporting its progress, running on runtime thread
+///
+/// ```ignore
+/// let (task, mut progress) = spawn_with_progress(|progress| async move {
+///     download_part(0).await;
+///     progress.send(0.5);
+///     download_part(1).await;
+///     progress.send(1.0);
+/// });
+///
+/// while let Some(value) = progress.next().await {
+///     progress_bar.set(value);
+/// }
+/// ```
+#[track_caller]
+pub fn spawn_with_progress<F, Fut>(f: F) -> (Task<Fut::Output>, ProgressStream)
+where
+    F: FnOnce(Progress) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send,
+{
+    let (sender, receiver) = ui_channel();
+
+    (
+        spawn_ui_task(f(Progress { sender })),
+        ProgressStream { receiver },
+    )
+}
+
+/// Progress reporter of task spawned with [`spawn_with_progress`]
+#[derive(Debug, Clone)]
+pub struct Progress {
+    sender: UiSender<f32>,
+}
+
+impl Progress {
+    /// Report progress, usually between `0.0` and `1.0`
+    ///
+    /// Ignored if [`ProgressStream`] is dropped.
+    pub fn send(&self, progress: f32) {
+        let _ = self.sender.send(progress);
+    }
+}
+
+/// Progress updates of task spawned with [`spawn_with_progress`]
+///
+/// Ends once task is finished and every [`Progress`] is dropped.
+#[derive(Debug)]
+pub struct ProgressStream {
+    receiver: UiReceiver<f32>,
+}
+
+impl ProgressStream {
+    /// Wait for next progress update, skipping to latest one if there are multiple updates pending
+    pub async fn next(&mut self) -> Option<f32> {
+        future::poll_fn(|cx| self.poll_latest(cx)).await
+    }
+
+    fn poll_latest(&mut self, cx: &mut Context<'_>) -> Poll<Option<f32>> {
+        let Some(mut latest) = ready!(Pin::new(&mut self.receiver).poll_next(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        loop {
+            match self.receiver.try_recv() {
+                Ok(progress) => latest = progress,
+                Err(TryRecvError::Empty | TryRecvError::Closed) => break Poll::Ready(Some(latest)),
+            }
+        }
+    }
+}
+
+impl Stream for ProgressStream {
+    type Item = f32;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<f32>> {
+        self.get_mut().poll_latest(cx)
+    }
+}
@@ -0,0 +1,111 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{SendError, Sender},
+        Arc,
+    },
+};
+
+use parking_lot::Mutex;
+use winit::{
+    event::Event,
+    event_loop::{EventLoopClosed, EventLoopProxy},
+};
+
+use super::event::ExecutorEvent;
+
+/// Proxy sending events to executor's event loop
+#[derive(Debug, Clone)]
+pub(crate) enum EventProxy {
+    EventLoop(EventLoopProxy<ExecutorEvent>),
+    Headless(Sender<Event<ExecutorEvent>>),
+}
+
+impl EventProxy {
+    #[allow(clippy::result_large_err)]
+    pub fn send_event(&self, event: ExecutorEvent) -> Result<(), EventLoopClosed<ExecutorEvent>> {
+        match self {
+            Self::EventLoop(proxy) => proxy.send_event(event),
+
+            Self::Headless(sender) => {
+                sender
+                    .send(Event::UserEvent(event))
+                    .map_err(|err| match err {
+                        SendError(Event::UserEvent(event)) => EventLoopClosed(event),
+                        _ => unreachable!(),
+                    })
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Proxy cloned for current thread, with address of its seed as runtime can be started again
+    static LOCAL_PROXY: RefCell<Option<(usize, EventProxy)>> = const { RefCell::new(None) };
+}
+
+/// [`EventProxy`] shared across threads
+///
+/// Each thread sends events using its own clone, so lock is only taken once per thread.
+#[derive(Debug, Clone)]
+pub(crate) struct SharedProxy {
+    seed: Arc<Mutex<EventProxy>>,
+
+    /// Wake event is sent and event loop is not woken yet
+    wake_pending: Arc<AtomicBool>,
+}
+
+impl SharedProxy {
+    pub fn new(proxy: EventProxy) -> Self {
+        Self {
+            seed: Arc::new(Mutex::new(proxy)),
+            wake_pending: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Wake event loop, coalescing wakes until event loop is woken
+    pub fn wake(&self) {
+        if !self.wake_pending.swap(true, Ordering::AcqRel)
+            && self.send_event(ExecutorEvent::Wake).is_err()
+        {
+            self.wake_pending.store(false, Ordering::Release);
+        }
+    }
+
+    /// Mark event loop woken, so next wake sends event again
+    ///
+    /// Called on new iteration and on each wake event, before woken tasks run.
+    pub fn woken(&self) {
+        self.wake_pending.store(false, Ordering::Release);
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn send_event(&self, event: ExecutorEvent) -> Result<(), EventLoopClosed<ExecutorEvent>> {
+        let mut event = Some(event);
+
+        let seed = Arc::as_ptr(&self.seed) as usize;
+        let res = LOCAL_PROXY.try_with(|local| {
+            let mut local = local.borrow_mut();
+
+            match *local {
+                Some((local_seed, ref proxy)) if local_seed == seed => proxy,
+                _ => &local.insert((seed, self.seed.lock().clone())).1,
+            }
+            .send_event(event.take().unwrap())
+        });
+
+        match res {
+            Ok(res) => res,
+
+            // Thread local storage is destroyed, fallback to shared proxy
+            Err(_) => self.seed.lock().send_event(event.take().unwrap()),
+        }
+    }
+}
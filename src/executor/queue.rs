@@ -0,0 +1,234 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Runnable queues of runtime thread, separated by priority lanes
+//!
+//! Runnables of deadline tasks are queued separately, and run before every lanes in order of their deadlines.
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use async_task::Runnable;
+use concurrent_queue::ConcurrentQueue;
+use instant::Instant;
+
+use crate::config::PollBudget;
+
+/// Task scheduling priority
+///
+/// Runnables with higher priority are run first in each event loop iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Latency sensitive work like input handling
+    High,
+
+    #[default]
+    Normal,
+
+    /// Background work which can be delayed
+    Low,
+}
+
+impl Priority {
+    const COUNT: usize = 3;
+
+    const fn lane(self) -> usize {
+        self as usize
+    }
+}
+
+/// Where runnable of a task is queued
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Lane {
+    Priority(Priority),
+
+    /// Run before every priority lanes, earliest deadline first
+    Deadline(Instant),
+}
+
+impl From<Priority> for Lane {
+    fn from(priority: Priority) -> Self {
+        Lane::Priority(priority)
+    }
+}
+
+/// Lock-free queue of runnables woken outside of runtime thread
+#[derive(Debug)]
+pub(super) struct RemoteQueue {
+    lanes: [ConcurrentQueue<Runnable>; Priority::COUNT],
+    deadlines: ConcurrentQueue<(Instant, Runnable)>,
+    pending: AtomicBool,
+    closed: AtomicBool,
+}
+
+impl RemoteQueue {
+    pub fn new() -> Self {
+        Self {
+            lanes: [
+                ConcurrentQueue::unbounded(),
+                ConcurrentQueue::unbounded(),
+                ConcurrentQueue::unbounded(),
+            ],
+            deadlines: ConcurrentQueue::unbounded(),
+            pending: AtomicBool::new(false),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Mark runtime shut down, so runnables are not queued anymore
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Queue runnable, returns `true` if event loop needs to be woken
+    pub fn push(&self, lane: Lane, runnable: Runnable) -> bool {
+        // Queue is never closed
+        match lane {
+            Lane::Priority(priority) => {
+                let _ = self.lanes[priority.lane()].push(runnable);
+            }
+
+            Lane::Deadline(deadline) => {
+                let _ = self.deadlines.push((deadline, runnable));
+            }
+        }
+
+        !self.pending.swap(true, Ordering::AcqRel)
+    }
+
+    /// Move every queued runnables into current thread's queue
+    pub fn drain(&self) {
+        // Reset first, so runnables pushed while draining wake event loop again
+        if !self.pending.swap(false, Ordering::AcqRel) {
+            return;
+        }
+
+        for (deadline, runnable) in self.deadlines.try_iter() {
+            push(Lane::Deadline(deadline), runnable);
+        }
+
+        for (priority, lane) in [Priority::High, Priority::Normal, Priority::Low]
+            .into_iter()
+            .zip(&self.lanes)
+        {
+            for runnable in lane.try_iter() {
+                push(priority.into(), runnable);
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Runnables scheduled on runtime thread, drained before returning control to event loop
+    static LOCAL_QUEUE: RefCell<[VecDeque<Runnable>; Priority::COUNT]> = const {
+        RefCell::new([VecDeque::new(), VecDeque::new(), VecDeque::new()])
+    };
+
+    /// Runnables of deadline tasks scheduled on runtime thread, sorted by deadline
+    static DEADLINE_QUEUE: RefCell<VecDeque<(Instant, Runnable)>> = const {
+        RefCell::new(VecDeque::new())
+    };
+}
+
+/// Queue runnable on current thread
+pub(super) fn push(lane: Lane, runnable: Runnable) {
+    match lane {
+        Lane::Priority(priority) => {
+            LOCAL_QUEUE.with(|queue| queue.borrow_mut()[priority.lane()].push_back(runnable))
+        }
+
+        Lane::Deadline(deadline) => DEADLINE_QUEUE.with(|queue| {
+            let mut queue = queue.borrow_mut();
+
+            // Keep order of runnables with same deadline
+            let index = queue.partition_point(|(queued, _)| *queued <= deadline);
+            queue.insert(index, (deadline, runnable));
+        }),
+    }
+}
+
+/// Remaining poll budget of current event loop iteration
+#[derive(Debug)]
+pub(super) struct Budget {
+    tasks: Option<usize>,
+    deadline: Option<Instant>,
+}
+
+impl Budget {
+    pub fn new(budget: PollBudget) -> Self {
+        Self {
+            tasks: budget.tasks,
+            deadline: budget.time.map(|time| Instant::now() + time),
+        }
+    }
+
+    pub fn exhausted(&self) -> bool {
+        self.tasks == Some(0)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    fn consume(&mut self) {
+        if let Some(ref mut tasks) = self.tasks {
+            *tasks = tasks.saturating_sub(1);
+        }
+    }
+}
+
+fn len() -> usize {
+    DEADLINE_QUEUE.with(|queue| queue.borrow().len())
+        + LOCAL_QUEUE.with(|queue| queue.borrow().iter().map(VecDeque::len).sum::<usize>())
+}
+
+fn pop() -> Option<Runnable> {
+    if let Some((_, runnable)) = DEADLINE_QUEUE.with(|queue| queue.borrow_mut().pop_front()) {
+        return Some(runnable);
+    }
+
+    LOCAL_QUEUE.with(|queue| queue.borrow_mut().iter_mut().find_map(VecDeque::pop_front))
+}
+
+/// Run runnables queued on current thread, deadline tasks first and then highest priority first.
+///
+/// Runs as many runnables as were queued before the call until budget is exhausted.
+pub(super) fn run(budget: &mut Budget) {
+    for _ in 0..len() {
+        if budget.exhausted() {
+            break;
+        }
+
+        match pop() {
+            Some(runnable) => {
+                budget.consume();
+                runnable.run();
+            }
+
+            None => break,
+        }
+    }
+}
+
+/// Check if there are runnables queued on current thread
+pub(super) fn has_pending() -> bool {
+    len() > 0
+}
+
+/// Drop every runnables queued on current thread
+pub(super) fn clear() {
+    let deadlines = DEADLINE_QUEUE.with(|queue| queue.take());
+    drop(deadlines);
+
+    let queue = LOCAL_QUEUE.with(|queue| queue.take());
+    drop(queue);
+}
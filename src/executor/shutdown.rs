@@ -0,0 +1,52 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Reporting of work dropped once runtime is shut down
+
+use parking_lot::{const_rwlock, RwLock};
+
+use super::tasks::TaskSnapshot;
+
+/// Work dropped as runtime is shut down already
+///
+/// See [`on_after_shutdown`]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum AfterShutdown {
+    /// Task is spawned or woken after shutdown, so it is cancelled instead of being scheduled
+    TaskScheduled(TaskSnapshot),
+
+    /// Closure is posted with [`post`](super::post) after shutdown
+    Posted,
+
+    /// Synthetic event could not be sent to closed event loop
+    EventDropped,
+
+    /// Tasks neither completed nor cancelled on exit, dropped on teardown. Main task is not included.
+    ///
+    /// Reported on runtime thread only if [`RuntimeConfig::report_leaked_tasks`](crate::config::RuntimeConfig::report_leaked_tasks) is enabled.
+    TasksLeaked(Vec<TaskSnapshot>),
+}
+
+type Hook = Box<dyn Fn(&AfterShutdown) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = const_rwlock(None);
+
+/// Set hook called every time work is dropped after runtime is shut down, replacing previous one
+///
+/// Hook is kept across runs of runtime, and called on the thread which tried to schedule the work.
+/// ```ignore
+/// executor::on_after_shutdown(|dropped| log::warn!("dropped after shutdown: {dropped:?}"));
+/// ```
+pub fn on_after_shutdown(hook: impl Fn(&AfterShutdown) + Send + Sync + 'static) {
+    *HOOK.write() = Some(Box::new(hook));
+}
+
+pub(super) fn report(dropped: AfterShutdown) {
+    if let Some(ref hook) = *HOOK.read() {
+        hook(&dropped);
+    }
+}
@@ -0,0 +1,172 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Watchdog reporting runtime thread blocked by long running listeners or tasks
+
+use instant::Duration;
+
+define_event!(
+    /// Runtime thread is blocked for the duration while handling one event, emitted on watchdog thread.
+    ///
+    /// See [`RuntimeConfig::stall_threshold`](crate::config::RuntimeConfig::stall_threshold)
+    pub stalled: Duration
+);
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(super) use native::StallWatchdog;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::{
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            mpsc::{self, RecvTimeoutError, Sender},
+            Arc,
+        },
+        thread,
+    };
+
+    use instant::{Duration, Instant};
+    use parking_lot::Mutex;
+
+    use crate::emit;
+
+    use super::stalled;
+
+    #[derive(Debug)]
+    pub(in crate::executor) struct StallWatchdog {
+        armed: AtomicBool,
+        state: Arc<BusyState>,
+        control: Mutex<Option<Sender<Duration>>>,
+    }
+
+    #[derive(Debug)]
+    struct BusyState {
+        epoch: Instant,
+
+        /// Microseconds since epoch plus one when runtime thread started handling current event, zero if idle
+        since: AtomicU64,
+    }
+
+    impl BusyState {
+        fn now(&self) -> u64 {
+            self.epoch.elapsed().as_micros() as u64 + 1
+        }
+    }
+
+    impl StallWatchdog {
+        pub fn new() -> Self {
+            Self {
+                armed: AtomicBool::new(false),
+                state: Arc::new(BusyState {
+                    epoch: Instant::now(),
+                    since: AtomicU64::new(0),
+                }),
+                control: Mutex::new(None),
+            }
+        }
+
+        /// Start, update or stop watchdog thread
+        pub fn set_threshold(&self, threshold: Option<Duration>) {
+            let mut control = self.control.lock();
+            self.armed.store(threshold.is_some(), Ordering::Relaxed);
+
+            let Some(threshold) = threshold else {
+                // Watchdog thread exits once sender is dropped
+                *control = None;
+                return;
+            };
+
+            if let Some(sender) = &*control {
+                if sender.send(threshold).is_ok() {
+                    return;
+                }
+            }
+
+            let (sender, receiver) = mpsc::channel();
+            let state = self.state.clone();
+            thread::Builder::new()
+                .name("winit-runtime-watchdog".into())
+                .spawn(move || {
+                    let mut threshold = threshold;
+                    let mut reported = 0;
+
+                    loop {
+                        match receiver.recv_timeout(threshold / 2) {
+                            Ok(new) => {
+                                threshold = new;
+                                continue;
+                            }
+                            Err(RecvTimeoutError::Disconnected) => break,
+                            Err(RecvTimeoutError::Timeout) => {}
+                        }
+
+                        // Report each blocking event once
+                        let since = state.since.load(Ordering::Acquire);
+                        if since == 0 || since == reported {
+                            continue;
+                        }
+
+                        let blocked = Duration::from_micros(state.now().saturating_sub(since));
+                        if blocked >= threshold {
+                            reported = since;
+                            emit!(stalled(), blocked);
+                        }
+                    }
+                })
+                .expect("failed to spawn watchdog thread");
+
+            *control = Some(sender);
+        }
+
+        /// Mark runtime thread busy until returned guard drops. Nested calls extend outermost one.
+        pub fn enter(&self) -> Busy<'_> {
+            if !self.armed.load(Ordering::Relaxed) {
+                return Busy(None);
+            }
+
+            let state = &*self.state;
+            match state
+                .since
+                .compare_exchange(0, state.now(), Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => Busy(Some(state)),
+                Err(_) => Busy(None),
+            }
+        }
+    }
+
+    pub(in crate::executor) struct Busy<'a>(Option<&'a BusyState>);
+
+    impl Drop for Busy<'_> {
+        fn drop(&mut self) {
+            if let Some(state) = self.0 {
+                state.since.store(0, Ordering::Release);
+            }
+        }
+    }
+}
+
+/// Threads are not available on wasm
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug)]
+pub(super) struct StallWatchdog;
+
+#[cfg(target_arch = "wasm32")]
+impl StallWatchdog {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn set_threshold(&self, _: Option<Duration>) {}
+
+    pub fn enter(&self) -> Busy {
+        Busy
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(super) struct Busy;
@@ -0,0 +1,223 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Registry of live tasks, for debugging
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    panic::Location,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use futures_lite::Future;
+use parking_lot::Mutex;
+use pin_project_lite::pin_project;
+
+/// State of a live task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskState {
+    /// Woken and waiting to be polled
+    Scheduled,
+
+    /// Being polled
+    Running,
+
+    /// Waiting to be woken
+    Idle,
+}
+
+impl TaskState {
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => TaskState::Scheduled,
+            1 => TaskState::Running,
+            _ => TaskState::Idle,
+        }
+    }
+}
+
+/// Snapshot of a live task
+///
+/// See [`dump_tasks`](super::dump_tasks)
+#[derive(Debug, Clone)]
+pub struct TaskSnapshot {
+    /// Unique id of the task
+    pub id: u64,
+
+    pub name: Option<Cow<'static, str>>,
+
+    pub state: TaskState,
+
+    /// Where the task is spawned
+    pub location: &'static Location<'static>,
+}
+
+#[derive(Debug)]
+pub(super) struct TaskEntry {
+    id: u64,
+    name: Option<Cow<'static, str>>,
+    location: &'static Location<'static>,
+    state: AtomicU8,
+
+    /// Waker of last poll, for cancelling idle task on teardown
+    waker: Mutex<Option<Waker>>,
+}
+
+impl TaskEntry {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Mark task woken, called on schedule
+    pub fn scheduled(&self) {
+        self.state
+            .store(TaskState::Scheduled as u8, Ordering::Release);
+    }
+
+    pub fn snapshot(&self) -> TaskSnapshot {
+        TaskSnapshot {
+            id: self.id,
+            name: self.name.clone(),
+            state: TaskState::from_u8(self.state.load(Ordering::Acquire)),
+            location: self.location,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct TaskRegistry {
+    next_id: AtomicU64,
+    tasks: Mutex<HashMap<u64, Arc<TaskEntry>>>,
+    cancelling: AtomicBool,
+}
+
+impl TaskRegistry {
+    /// Register new task, returns its entry
+    pub fn register(
+        &self,
+        name: Option<Cow<'static, str>>,
+        location: &'static Location<'static>,
+    ) -> Arc<TaskEntry> {
+        let entry = Arc::new(TaskEntry {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            name,
+            location,
+            state: AtomicU8::new(TaskState::Idle as u8),
+            waker: Mutex::new(None),
+        });
+        self.tasks.lock().insert(entry.id, entry.clone());
+
+        entry
+    }
+
+    pub fn snapshot(&self) -> Vec<TaskSnapshot> {
+        let mut tasks = self
+            .tasks
+            .lock()
+            .values()
+            .map(|entry| entry.snapshot())
+            .collect::<Vec<_>>();
+        tasks.sort_unstable_by_key(|task| task.id);
+
+        tasks
+    }
+
+    /// Check if tasks are being cancelled by [`TaskRegistry::cancel_except`]
+    pub fn is_cancelling(&self) -> bool {
+        self.cancelling.load(Ordering::Acquire)
+    }
+
+    /// Wake every task except given one, so their runnables are dropped once scheduler is closed
+    pub fn cancel_except(&self, id: u64) {
+        let wakers = self
+            .tasks
+            .lock()
+            .values()
+            .filter(|entry| entry.id != id)
+            .filter_map(|entry| entry.waker.lock().take())
+            .collect::<Vec<_>>();
+
+        // Wake without holding lock, as tasks unregister themselves once dropped
+        self.cancelling.store(true, Ordering::Release);
+        for waker in wakers {
+            waker.wake();
+        }
+        self.cancelling.store(false, Ordering::Release);
+    }
+}
+
+pin_project! {
+    /// Future tracking state of its task, unregistered once dropped
+    pub(super) struct Tracked<Fut> {
+        #[pin]
+        fut: Fut,
+        entry: Arc<TaskEntry>,
+        registry: Arc<TaskRegistry>,
+    }
+
+    impl<Fut> PinnedDrop for Tracked<Fut> {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+            this.registry.tasks.lock().remove(&this.entry.id);
+
+            // Waker holds the task, which holds this entry through its schedule closure
+            let waker = this.entry.waker.lock().take();
+            drop(waker);
+        }
+    }
+}
+
+impl<Fut> Tracked<Fut> {
+    pub fn new(fut: Fut, entry: Arc<TaskEntry>, registry: Arc<TaskRegistry>) -> Self {
+        Self {
+            fut,
+            entry,
+            registry,
+        }
+    }
+}
+
+impl<Fut: Future> Future for Tracked<Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        this.entry
+            .state
+            .store(TaskState::Running as u8, Ordering::Release);
+        let poll = this.fut.poll(cx);
+
+        if poll.is_pending() {
+            let mut waker = this.entry.waker.lock();
+            if !waker
+                .as_ref()
+                .is_some_and(|waker| waker.will_wake(cx.waker()))
+            {
+                *waker = Some(cx.waker().clone());
+            }
+        } else {
+            let waker = this.entry.waker.lock().take();
+            drop(waker);
+        }
+
+        // Keep scheduled state if task is woken while running
+        let _ = this.entry.state.compare_exchange(
+            TaskState::Running as u8,
+            TaskState::Idle as u8,
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        );
+
+        poll
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Gamepad input using gilrs
+//!
+//! gilrs is polled on runtime thread every event loop iteration while there are [`gamepad`] listeners,
+//! waking event loop periodically to do so.
+
+use std::cell::RefCell;
+
+use gilrs::{Button, Event, EventType, GamepadId, Gilrs};
+use instant::Duration;
+
+use crate::emit;
+
+pub use gilrs;
+
+/// Polling interval of gilrs while there are listeners
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_millis(8);
+
+define_event!(
+    /// Gamepad events
+    pub gamepad: &Event
+);
+
+thread_local! {
+    /// Lazily initialized gilrs, [`None`] if initialization failed
+    static GILRS: RefCell<Option<Option<Gilrs>>> = const { RefCell::new(None) };
+}
+
+/// Run closure using gilrs, for querying gamepad states
+///
+/// Returns [`None`] if gilrs is unavailable. Must be called on runtime thread.
+pub fn with_gilrs<R>(f: impl FnOnce(&mut Gilrs) -> R) -> Option<R> {
+    GILRS.with(|gilrs| {
+        gilrs
+            .borrow_mut()
+            .get_or_insert_with(|| Gilrs::new().ok())
+            .as_mut()
+            .map(f)
+    })
+}
+
+/// Wait for next button press of any gamepads
+pub async fn next_button_press() -> (GamepadId, Button) {
+    gamepad()
+        .wait_map(|event| match event.event {
+            EventType::ButtonPressed(button, _) => Some((event.id, button)),
+            _ => None,
+        })
+        .await
+}
+
+/// Emit pending gilrs events, returns `true` if gamepad should be polled again
+pub(crate) fn pump() -> bool {
+    if gamepad().listener_count() == 0 {
+        return false;
+    }
+
+    // Gilrs is not borrowed while emitting, so listeners can use it
+    while let Some(Some(event)) = with_gilrs(Gilrs::next_event) {
+        emit!(gamepad(), &event);
+    }
+
+    with_gilrs(|_| ()).is_some()
+}
@@ -0,0 +1,223 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! wgpu integration
+//!
+//! ```ignore
+//! let (surface, context) = gfx::create_surface(window).await?;
+//! spawn_local_ui_task(async move { surface.maintain(&context).await }).detach();
+//! ```
+
+use std::{error::Error, fmt, sync::Arc};
+
+use futures_lite::future;
+use parking_lot::{Mutex, MutexGuard};
+use wgpu::{
+    Adapter, CreateSurfaceError, Device, DeviceDescriptor, Instance, Queue, RequestAdapterOptions,
+    RequestDeviceError, Surface, SurfaceConfiguration, SurfaceError, SurfaceTexture,
+};
+use winit::{dpi::PhysicalSize, event::WindowEvent, window::Window};
+
+use crate::{resumed, suspended, window};
+
+/// Graphics device shared by surfaces
+#[derive(Debug)]
+pub struct GfxContext {
+    pub instance: Instance,
+    pub adapter: Adapter,
+    pub device: Device,
+    pub queue: Queue,
+}
+
+/// Surface of a window, kept valid by [`WindowSurface::maintain`]
+#[derive(Debug)]
+pub struct WindowSurface {
+    window: Arc<Window>,
+    surface: Mutex<Option<Surface<'static>>>,
+    config: Mutex<SurfaceConfiguration>,
+}
+
+impl WindowSurface {
+    pub fn window(&self) -> &Arc<Window> {
+        &self.window
+    }
+
+    /// Current surface configuration
+    pub fn config(&self) -> MutexGuard<'_, SurfaceConfiguration> {
+        self.config.lock()
+    }
+
+    /// Get next texture to present
+    ///
+    /// Returns [`SurfaceError::Lost`] while application is suspended
+    pub fn current_texture(&self) -> Result<SurfaceTexture, SurfaceError> {
+        match *self.surface.lock() {
+            Some(ref surface) => surface.get_current_texture(),
+            None => Err(SurfaceError::Lost),
+        }
+    }
+
+    /// Reconfigure surface with given configuration
+    pub fn configure(&self, device: &Device, config: SurfaceConfiguration) {
+        if let Some(ref surface) = *self.surface.lock() {
+            configure(surface, device, &config);
+        }
+
+        *self.config.lock() = config;
+    }
+
+    fn resize(&self, device: &Device, size: PhysicalSize<u32>) {
+        let mut config = self.config.lock();
+        config.width = size.width.max(1);
+        config.height = size.height.max(1);
+
+        if let Some(ref surface) = *self.surface.lock() {
+            configure(surface, device, &config);
+        }
+    }
+
+    /// Keep surface valid, reconfiguring on resize and recreating on suspend and resume
+    pub async fn maintain(&self, context: &GfxContext) -> ! {
+        enum Change {
+            Resize(PhysicalSize<u32>),
+            Rescale,
+            Suspend,
+            Resume,
+        }
+
+        let id = self.window.id();
+
+        loop {
+            let Some(change) = future::or(
+                window().once(move |(window_id, event), _| match event {
+                    WindowEvent::Resized(size) if window_id == id => Some(Change::Resize(*size)),
+                    WindowEvent::ScaleFactorChanged { .. } if window_id == id => {
+                        Some(Change::Rescale)
+                    }
+                    _ => None,
+                }),
+                future::or(
+                    suspended().once(|_, _| Some(Change::Suspend)),
+                    resumed().once(|_, _| Some(Change::Resume)),
+                ),
+            )
+            .await
+            else {
+                continue;
+            };
+
+            match change {
+                Change::Resize(size) => self.resize(&context.device, size),
+
+                // Inner size is negotiated during dispatch, read it after
+                Change::Rescale => self.resize(&context.device, self.window.inner_size()),
+
+                Change::Suspend => {
+                    self.surface.lock().take();
+                }
+
+                Change::Resume => {
+                    if self.surface.lock().is_some() {
+                        continue;
+                    }
+
+                    if let Ok(surface) = context.instance.create_surface(self.window.clone()) {
+                        *self.surface.lock() = Some(surface);
+                        self.resize(&context.device, self.window.inner_size());
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn configure(surface: &Surface, device: &Device, config: &SurfaceConfiguration) {
+    if config.width > 0 && config.height > 0 {
+        surface.configure(device, config);
+    }
+}
+
+/// Create surface for window along with compatible graphics device
+pub async fn create_surface(window: Arc<Window>) -> Result<(WindowSurface, GfxContext), GfxError> {
+    let instance = Instance::default();
+    let surface = instance.create_surface(window.clone())?;
+
+    let adapter = instance
+        .request_adapter(&RequestAdapterOptions {
+            compatible_surface: Some(&surface),
+            ..Default::default()
+        })
+        .await
+        .ok_or(GfxError::NoAdapter)?;
+
+    let (device, queue) = adapter
+        .request_device(&DeviceDescriptor::default(), None)
+        .await?;
+
+    let size = window.inner_size();
+    let config = surface
+        .get_default_config(&adapter, size.width.max(1), size.height.max(1))
+        .ok_or(GfxError::Unsupported)?;
+    configure(&surface, &device, &config);
+
+    Ok((
+        WindowSurface {
+            window,
+            surface: Mutex::new(Some(surface)),
+            config: Mutex::new(config),
+        },
+        GfxContext {
+            instance,
+            adapter,
+            device,
+            queue,
+        },
+    ))
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GfxError {
+    CreateSurface(CreateSurfaceError),
+    /// No adapter compatible with the surface
+    NoAdapter,
+    RequestDevice(RequestDeviceError),
+    /// Surface is not supported by the adapter
+    Unsupported,
+}
+
+impl fmt::Display for GfxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GfxError::CreateSurface(err) => write!(f, "failed to create surface: {err}"),
+            GfxError::NoAdapter => f.write_str("no compatible adapter found"),
+            GfxError::RequestDevice(err) => write!(f, "failed to request device: {err}"),
+            GfxError::Unsupported => f.write_str("surface is not supported by the adapter"),
+        }
+    }
+}
+
+impl Error for GfxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GfxError::CreateSurface(err) => Some(err),
+            GfxError::RequestDevice(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<CreateSurfaceError> for GfxError {
+    fn from(err: CreateSurfaceError) -> Self {
+        GfxError::CreateSurface(err)
+    }
+}
+
+impl From<RequestDeviceError> for GfxError {
+    fn from(err: RequestDeviceError) -> Self {
+        GfxError::RequestDevice(err)
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Input helpers
+
+#[cfg(feature = "device-events")]
+mod motion;
+pub mod shortcuts;
+mod text;
+
+#[cfg(feature = "device-events")]
+pub use motion::{raw_motion, RawMotion};
+
+pub use text::{chars, Chars};
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::OnceLock,
+};
+
+use parking_lot::Mutex;
+use winit::{
+    dpi::PhysicalPosition,
+    error::ExternalError,
+    event::{ElementState, MouseButton, WindowEvent},
+    keyboard::{ModifiersState, PhysicalKey},
+    window::{CursorGrabMode, Window, WindowId},
+};
+
+/// Grab and hide cursor for FPS style camera control, waiting until window is focused first
+///
+/// Falls back to [`CursorGrabMode::Confined`] if [`CursorGrabMode::Locked`] is not supported and vice versa.
+/// Cursor is released and shown again once returned [`CursorGrab`] is dropped.
+pub async fn grab_cursor(
+    window: &Window,
+    mode: CursorGrabMode,
+) -> Result<CursorGrab<'_>, ExternalError> {
+    // Grabbing unfocused window fails on some platforms
+    if !window.has_focus() {
+        let id = window.id();
+
+        crate::window()
+            .wait_map(move |(window_id, event)| {
+                (window_id == id && matches!(event, WindowEvent::Focused(true))).then_some(())
+            })
+            .await;
+    }
+
+    let fallback = match mode {
+        CursorGrabMode::None => CursorGrabMode::None,
+        CursorGrabMode::Confined => CursorGrabMode::Locked,
+        CursorGrabMode::Locked => CursorGrabMode::Confined,
+    };
+
+    let mode = match window.set_cursor_grab(mode) {
+        Ok(_) => mode,
+        Err(_) => {
+            window.set_cursor_grab(fallback)?;
+            fallback
+        }
+    };
+    window.set_cursor_visible(false);
+
+    Ok(CursorGrab { window, mode })
+}
+
+/// Cursor grab of a window created with [`grab_cursor`]
+#[derive(Debug)]
+pub struct CursorGrab<'a> {
+    window: &'a Window,
+    mode: CursorGrabMode,
+}
+
+impl CursorGrab<'_> {
+    /// Grab mode applied
+    pub const fn mode(&self) -> CursorGrabMode {
+        self.mode
+    }
+
+    /// Stream of raw mouse motions, not limited by window bounds
+    #[cfg(feature = "device-events")]
+    pub fn motion(&self) -> RawMotion {
+        raw_motion()
+    }
+}
+
+impl Drop for CursorGrab<'_> {
+    fn drop(&mut self) {
+        let _ = self.window.set_cursor_grab(CursorGrabMode::None);
+        self.window.set_cursor_visible(true);
+    }
+}
+
+/// Snapshot of keyboard and mouse state, aggregated from window events
+///
+/// See [`snapshot`]
+#[derive(Debug, Clone, Default)]
+pub struct InputSnapshot {
+    keys: HashSet<PhysicalKey>,
+    buttons: HashSet<MouseButton>,
+    modifiers: ModifiersState,
+    cursors: HashMap<WindowId, PhysicalPosition<f64>>,
+}
+
+impl InputSnapshot {
+    pub fn is_key_pressed(&self, key: impl Into<PhysicalKey>) -> bool {
+        self.keys.contains(&key.into())
+    }
+
+    pub fn pressed_keys(&self) -> impl Iterator<Item = PhysicalKey> + '_ {
+        self.keys.iter().copied()
+    }
+
+    pub fn is_button_pressed(&self, button: MouseButton) -> bool {
+        self.buttons.contains(&button)
+    }
+
+    pub const fn modifiers(&self) -> ModifiersState {
+        self.modifiers
+    }
+
+    /// Last cursor position on the window, [`None`] if cursor is outside of it
+    pub fn cursor_position(&self, window_id: WindowId) -> Option<PhysicalPosition<f64>> {
+        self.cursors.get(&window_id).copied()
+    }
+
+    fn update(&mut self, window_id: WindowId, event: &WindowEvent) {
+        match *event {
+            WindowEvent::KeyboardInput { ref event, .. } => match event.state {
+                ElementState::Pressed => {
+                    self.keys.insert(event.physical_key);
+                }
+
+                ElementState::Released => {
+                    self.keys.remove(&event.physical_key);
+                }
+            },
+
+            WindowEvent::ModifiersChanged(modifiers) => self.modifiers = modifiers.state(),
+
+            WindowEvent::MouseInput { state, button, .. } => match state {
+                ElementState::Pressed => {
+                    self.buttons.insert(button);
+                }
+
+                ElementState::Released => {
+                    self.buttons.remove(&button);
+                }
+            },
+
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursors.insert(window_id, position);
+            }
+
+            WindowEvent::CursorLeft { .. } | WindowEvent::Destroyed => {
+                self.cursors.remove(&window_id);
+            }
+
+            // Release events are not delivered to unfocused windows
+            WindowEvent::Focused(false) => {
+                self.keys.clear();
+                self.buttons.clear();
+                self.modifiers = ModifiersState::empty();
+            }
+
+            _ => {}
+        }
+    }
+}
+
+fn input_state() -> &'static Mutex<InputSnapshot> {
+    static STATE: OnceLock<Mutex<InputSnapshot>> = OnceLock::new();
+
+    STATE.get_or_init(Default::default)
+}
+
+fn snapshot_modifiers() -> ModifiersState {
+    input_state().lock().modifiers
+}
+
+pub(crate) fn reset() {
+    *input_state().lock() = InputSnapshot::default();
+}
+
+/// Take snapshot of current keyboard and mouse state, for polling once per frame
+///
+/// State is updated before each window event is dispatched, so it is consistent with events listeners received.
+pub fn snapshot() -> InputSnapshot {
+    input_state().lock().clone()
+}
+
+/// Update input state with window event
+pub(crate) fn process_event(window_id: WindowId, event: &WindowEvent) {
+    input_state().lock().update(window_id, event);
+}
@@ -0,0 +1,74 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_lite::Stream;
+use winit::event::DeviceEvent;
+
+use crate::{
+    device, spawn_ui_task,
+    sync::{ui_channel, UiReceiver},
+    task::Task,
+};
+
+/// Listen raw mouse motions from [`device`] events
+///
+/// Motions are buffered until they are received. Stops listening once dropped.
+pub fn raw_motion() -> RawMotion {
+    let (sender, receiver) = ui_channel();
+
+    let task = spawn_ui_task(device().on(move |(_, event), _| {
+        if let DeviceEvent::MouseMotion { delta } = event {
+            let _ = sender.send(*delta);
+        }
+    }));
+
+    RawMotion {
+        _task: task,
+        receiver,
+    }
+}
+
+/// Stream of raw mouse motion deltas
+#[derive(Debug)]
+pub struct RawMotion {
+    _task: Task<()>,
+    receiver: UiReceiver<(f64, f64)>,
+}
+
+impl RawMotion {
+    /// Wait for next motion delta
+    ///
+    /// Returns [`None`] once every received delta is taken after listener stopped, like when runtime exited.
+    pub async fn next(&mut self) -> Option<(f64, f64)> {
+        // Sender lives until listening task is dropped
+        self.receiver.recv().await
+    }
+
+    /// Take sum of every deltas received since last call, without waiting
+    pub fn accumulated(&mut self) -> (f64, f64) {
+        let mut sum = (0.0, 0.0);
+
+        while let Ok((x, y)) = self.receiver.try_recv() {
+            sum.0 += x;
+            sum.1 += y;
+        }
+
+        sum
+    }
+}
+
+impl Stream for RawMotion {
+    type Item = (f64, f64);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<(f64, f64)>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
@@ -0,0 +1,402 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Keyboard shortcuts registry
+//!
+//! Shortcuts are matched against key presses of every window with modifiers tracked by runtime,
+//! and emitted on their event sources after the key event is dispatched.
+//!
+//! ```ignore
+//! let save = shortcuts::register("Ctrl+S")?;
+//!
+//! spawn_ui_task(save.on(|window_id, _| save_document(window_id))).detach();
+//! ```
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use parking_lot::{const_mutex, Mutex};
+use winit::{
+    event::{ElementState, KeyEvent, WindowEvent},
+    keyboard::{Key, KeyCode, ModifiersState, NamedKey, PhysicalKey, SmolStr},
+    window::WindowId,
+};
+
+use crate::{emit, EventSource};
+
+/// Event source of a registered shortcut, emitted with window the shortcut is pressed on
+pub type ShortcutSource = EventSource!(WindowId);
+
+/// Key of a [`Shortcut`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Trigger {
+    /// Layout aware key, matched with key produced by current keyboard layout
+    ///
+    /// Latin letters and digits are matched with physical key of US layout instead,
+    /// if current layout does not produce latin character for the key.
+    Key(Key),
+
+    /// Layout independent physical key
+    Code(KeyCode),
+}
+
+/// Keyboard shortcut, like `Ctrl+S`
+///
+/// Can be parsed from string with `+` separated modifiers followed by a key.
+/// Modifiers are `Ctrl`, `Shift`, `Alt`, `Super` and `CmdOrCtrl`, which is `Super` on macOS and `Ctrl` otherwise.
+/// Keys are single characters or names of [`NamedKey`] like `Enter`, `Escape` or `F5`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    modifiers: ModifiersState,
+    trigger: Trigger,
+}
+
+impl Shortcut {
+    /// Create shortcut. Characters of [`Trigger::Key`] are matched case-insensitively.
+    pub fn new(modifiers: ModifiersState, trigger: Trigger) -> Self {
+        let trigger = match trigger {
+            Trigger::Key(Key::Character(ch)) => {
+                Trigger::Key(Key::Character(ch.to_lowercase().into()))
+            }
+            trigger => trigger,
+        };
+
+        Self { modifiers, trigger }
+    }
+
+    pub const fn modifiers(&self) -> ModifiersState {
+        self.modifiers
+    }
+
+    pub const fn trigger(&self) -> &Trigger {
+        &self.trigger
+    }
+
+    /// Check if both shortcuts can be triggered by same key press
+    pub fn overlaps(&self, other: &Shortcut) -> bool {
+        if self.modifiers != other.modifiers {
+            return false;
+        }
+
+        match (&self.trigger, &other.trigger) {
+            (Trigger::Key(Key::Character(ch)), Trigger::Code(code))
+            | (Trigger::Code(code), Trigger::Key(Key::Character(ch))) => {
+                latin_code(ch) == Some(*code)
+            }
+
+            (a, b) => a == b,
+        }
+    }
+
+    /// Check if key event with given modifiers triggers the shortcut
+    pub fn matches(&self, event: &KeyEvent, modifiers: ModifiersState) -> bool {
+        if modifiers != self.modifiers {
+            return false;
+        }
+
+        match self.trigger {
+            Trigger::Code(code) => event.physical_key == PhysicalKey::Code(code),
+
+            Trigger::Key(Key::Character(ref ch)) => match event.logical_key {
+                Key::Character(ref logical) if logical.is_ascii() => {
+                    logical.to_lowercase() == ch.as_str()
+                }
+
+                // Non latin layouts
+                _ => {
+                    latin_code(ch).is_some_and(|code| event.physical_key == PhysicalKey::Code(code))
+                }
+            },
+
+            Trigger::Key(ref key) => event.logical_key == *key,
+        }
+    }
+}
+
+impl Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (ModifiersState::CONTROL, "Ctrl"),
+            (ModifiersState::SHIFT, "Shift"),
+            (ModifiersState::ALT, "Alt"),
+            (ModifiersState::SUPER, "Super"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match self.trigger {
+            Trigger::Key(Key::Character(ref ch)) => f.write_str(&ch.to_uppercase()),
+            Trigger::Key(Key::Named(key)) => write!(f, "{key:?}"),
+            Trigger::Key(ref key) => write!(f, "{key:?}"),
+            Trigger::Code(code) => write!(f, "{code:?}"),
+        }
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Trailing `+` is plus key
+        let (modifiers, key) = match s.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+
+        let mut state = ModifiersState::empty();
+        for modifier in modifiers.split('+').filter(|modifier| !modifier.is_empty()) {
+            state |= match modifier.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => ModifiersState::CONTROL,
+                "shift" => ModifiersState::SHIFT,
+                "alt" | "option" => ModifiersState::ALT,
+                "super" | "cmd" | "command" | "meta" | "win" => ModifiersState::SUPER,
+
+                "cmdorctrl" if cfg!(target_os = "macos") => ModifiersState::SUPER,
+                "cmdorctrl" => ModifiersState::CONTROL,
+
+                _ => return Err(ShortcutError::Invalid),
+            };
+        }
+
+        Ok(Self::new(state, Trigger::Key(parse_key(key.trim())?)))
+    }
+}
+
+fn parse_key(key: &str) -> Result<Key, ShortcutError> {
+    let mut chars = key.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return Ok(match ch {
+            ' ' => Key::Named(NamedKey::Space),
+            ch => Key::Character(SmolStr::new(ch.encode_utf8(&mut [0; 4]))),
+        });
+    }
+
+    let named = match key.to_ascii_lowercase().as_str() {
+        "enter" | "return" => NamedKey::Enter,
+        "escape" | "esc" => NamedKey::Escape,
+        "tab" => NamedKey::Tab,
+        "space" => NamedKey::Space,
+        "backspace" => NamedKey::Backspace,
+        "delete" | "del" => NamedKey::Delete,
+        "insert" | "ins" => NamedKey::Insert,
+        "home" => NamedKey::Home,
+        "end" => NamedKey::End,
+        "pageup" => NamedKey::PageUp,
+        "pagedown" => NamedKey::PageDown,
+        "up" | "arrowup" => NamedKey::ArrowUp,
+        "down" | "arrowdown" => NamedKey::ArrowDown,
+        "left" | "arrowleft" => NamedKey::ArrowLeft,
+        "right" | "arrowright" => NamedKey::ArrowRight,
+        "plus" => return Ok(Key::Character("+".into())),
+        "minus" => return Ok(Key::Character("-".into())),
+
+        name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+            Some(n @ 1..=24) => [
+                NamedKey::F1,
+                NamedKey::F2,
+                NamedKey::F3,
+                NamedKey::F4,
+                NamedKey::F5,
+                NamedKey::F6,
+                NamedKey::F7,
+                NamedKey::F8,
+                NamedKey::F9,
+                NamedKey::F10,
+                NamedKey::F11,
+                NamedKey::F12,
+                NamedKey::F13,
+                NamedKey::F14,
+                NamedKey::F15,
+                NamedKey::F16,
+                NamedKey::F17,
+                NamedKey::F18,
+                NamedKey::F19,
+                NamedKey::F20,
+                NamedKey::F21,
+                NamedKey::F22,
+                NamedKey::F23,
+                NamedKey::F24,
+            ][n as usize - 1],
+
+            _ => return Err(ShortcutError::Invalid),
+        },
+    };
+
+    Ok(Key::Named(named))
+}
+
+/// Physical key of latin letter or digit on US layout
+fn latin_code(ch: &str) -> Option<KeyCode> {
+    const LETTERS: [KeyCode; 26] = [
+        KeyCode::KeyA,
+        KeyCode::KeyB,
+        KeyCode::KeyC,
+        KeyCode::KeyD,
+        KeyCode::KeyE,
+        KeyCode::KeyF,
+        KeyCode::KeyG,
+        KeyCode::KeyH,
+        KeyCode::KeyI,
+        KeyCode::KeyJ,
+        KeyCode::KeyK,
+        KeyCode::KeyL,
+        KeyCode::KeyM,
+        KeyCode::KeyN,
+        KeyCode::KeyO,
+        KeyCode::KeyP,
+        KeyCode::KeyQ,
+        KeyCode::KeyR,
+        KeyCode::KeyS,
+        KeyCode::KeyT,
+        KeyCode::KeyU,
+        KeyCode::KeyV,
+        KeyCode::KeyW,
+        KeyCode::KeyX,
+        KeyCode::KeyY,
+        KeyCode::KeyZ,
+    ];
+
+    const DIGITS: [KeyCode; 10] = [
+        KeyCode::Digit0,
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+        KeyCode::Digit9,
+    ];
+
+    match *ch.as_bytes() {
+        [ch @ b'a'..=b'z'] => Some(LETTERS[(ch - b'a') as usize]),
+        [ch @ b'0'..=b'9'] => Some(DIGITS[(ch - b'0') as usize]),
+        _ => None,
+    }
+}
+
+/// Error of shortcut registration
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ShortcutError {
+    /// Shortcut string is invalid
+    Invalid,
+
+    /// Shortcut overlapping with it is registered already
+    Conflict(Shortcut),
+}
+
+impl Display for ShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShortcutError::Invalid => f.write_str("invalid shortcut"),
+            ShortcutError::Conflict(shortcut) => {
+                write!(f, "shortcut {shortcut} is registered already")
+            }
+        }
+    }
+}
+
+impl Error for ShortcutError {}
+
+#[derive(Debug)]
+struct Entry {
+    source: &'static ShortcutSource,
+    registered: bool,
+}
+
+static SHORTCUTS: Mutex<Option<HashMap<Shortcut, Entry>>> = const_mutex(None);
+
+/// Register shortcut parsed from string, returns event source emitted with window the shortcut is pressed on
+///
+/// Fails if shortcut is registered already. See [`Shortcut`] for syntax.
+pub fn register(shortcut: &str) -> Result<&'static ShortcutSource, ShortcutError> {
+    register_shortcut(shortcut.parse()?)
+}
+
+/// Register shortcut, returns event source emitted with window the shortcut is pressed on
+///
+/// Fails if any shortcut overlapping with it is registered already, see [`Shortcut::overlaps`].
+/// Event source of a shortcut is same after registering it again.
+pub fn register_shortcut(shortcut: Shortcut) -> Result<&'static ShortcutSource, ShortcutError> {
+    let mut shortcuts = SHORTCUTS.lock();
+    let shortcuts = shortcuts.get_or_insert_with(HashMap::new);
+
+    if let Some((registered, _)) = shortcuts
+        .iter()
+        .find(|(registered, entry)| entry.registered && registered.overlaps(&shortcut))
+    {
+        return Err(ShortcutError::Conflict(registered.clone()));
+    }
+
+    match shortcuts.get_mut(&shortcut) {
+        Some(entry) => {
+            entry.registered = true;
+            Ok(entry.source)
+        }
+
+        None => {
+            let source: &'static ShortcutSource =
+                Box::leak(Box::new(crate::event::EventSource::new()));
+
+            shortcuts.insert(
+                shortcut,
+                Entry {
+                    source,
+                    registered: true,
+                },
+            );
+            Ok(source)
+        }
+    }
+}
+
+/// Unregister shortcut, returns `false` if it was not registered
+pub fn unregister(shortcut: &Shortcut) -> bool {
+    SHORTCUTS
+        .lock()
+        .as_mut()
+        .and_then(|shortcuts| shortcuts.get_mut(shortcut))
+        .is_some_and(|entry| std::mem::replace(&mut entry.registered, false))
+}
+
+pub fn is_registered(shortcut: &Shortcut) -> bool {
+    SHORTCUTS
+        .lock()
+        .as_ref()
+        .and_then(|shortcuts| shortcuts.get(shortcut))
+        .is_some_and(|entry| entry.registered)
+}
+
+/// Emit shortcuts triggered by window event
+pub(crate) fn process_event(window_id: WindowId, event: &WindowEvent) {
+    let WindowEvent::KeyboardInput { ref event, .. } = *event else {
+        return;
+    };
+
+    if event.state != ElementState::Pressed || event.repeat {
+        return;
+    }
+
+    let modifiers = super::snapshot_modifiers();
+    let triggered = SHORTCUTS.lock().as_ref().and_then(|shortcuts| {
+        shortcuts
+            .iter()
+            .find(|(shortcut, entry)| entry.registered && shortcut.matches(event, modifiers))
+            .map(|(_, entry)| entry.source)
+    });
+
+    if let Some(source) = triggered {
+        emit!(source, window_id);
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_lite::Stream;
+use winit::{
+    event::{ElementState, Ime, WindowEvent},
+    window::WindowId,
+};
+
+use crate::{
+    spawn_ui_task,
+    sync::{ui_channel, UiReceiver},
+    task::Task,
+};
+
+/// Listen characters typed on the window, including key repeats
+///
+/// Text of key presses is skipped while IME is composing, and committed IME text is received instead.
+/// Control characters like backspace (`'\u{8}'`) and enter (`'\r'`) are received as is.
+/// Stream ends once window is destroyed. Stops listening once dropped.
+pub fn chars(window_id: WindowId) -> Chars {
+    let (sender, receiver) = ui_channel();
+
+    let task = spawn_ui_task({
+        let mut composing = false;
+
+        crate::window().on(move |(id, event), flow| {
+            if id != window_id || flow.done() {
+                return;
+            }
+
+            match event {
+                WindowEvent::KeyboardInput { event, .. }
+                    if event.state == ElementState::Pressed && !composing =>
+                {
+                    if let Some(text) = &event.text {
+                        text.chars().for_each(|ch| {
+                            let _ = sender.send(ch);
+                        });
+                    }
+                }
+
+                WindowEvent::Ime(Ime::Preedit(text, _)) => composing = !text.is_empty(),
+
+                WindowEvent::Ime(Ime::Commit(text)) => {
+                    composing = false;
+                    text.chars().for_each(|ch| {
+                        let _ = sender.send(ch);
+                    });
+                }
+
+                WindowEvent::Ime(Ime::Disabled) | WindowEvent::Focused(false) => composing = false,
+
+                WindowEvent::Destroyed => flow.set_done(),
+
+                _ => {}
+            }
+        })
+    });
+
+    Chars {
+        _task: task,
+        receiver,
+    }
+}
+
+/// Stream of characters typed on a window
+#[derive(Debug)]
+pub struct Chars {
+    _task: Task<()>,
+    receiver: UiReceiver<char>,
+}
+
+impl Chars {
+    /// Wait for next character, returns [`None`] if window is destroyed
+    pub async fn next(&mut self) -> Option<char> {
+        self.receiver.recv().await
+    }
+
+    /// Take every characters received since last call as string, without waiting
+    pub fn collect_pending(&mut self) -> String {
+        let mut text = String::new();
+
+        while let Ok(ch) = self.receiver.try_recv() {
+            text.push(ch);
+        }
+
+        text
+    }
+}
+
+impl Stream for Chars {
+    type Item = char;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<char>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
@@ -0,0 +1,77 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Network and file IO for ui tasks on native platforms
+//!
+//! Network IO is driven by [`async-io`](async_io) reactor running on its own helper thread, started on first use.
+//! Ready IO wakes ui tasks through event loop proxy like any other wake from other threads, so no second runtime is needed.
+//! File IO runs on blocking threads with [`spawn_blocking`].
+//! ```ignore
+//! spawn_ui_task(async {
+//!     let mut stream = io::connect(([127, 0, 0, 1], 8080)).await?;
+//!     stream.write_all(b"hello").await?;
+//!
+//!     let config = io::read("config.toml").await?;
+//! })
+//! .detach();
+//! ```
+
+use std::{
+    fs, io,
+    net::{SocketAddr, TcpListener, TcpStream, UdpSocket},
+    path::{Path, PathBuf},
+};
+
+pub use async_io::{Async, IoSafe};
+pub use futures_lite::io::{
+    AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite,
+    AsyncWriteExt,
+};
+
+use crate::spawn_blocking;
+
+/// Connect to TCP server
+pub async fn connect(addr: impl Into<SocketAddr>) -> io::Result<Async<TcpStream>> {
+    Async::<TcpStream>::connect(addr).await
+}
+
+/// Create TCP listener bound to the address
+pub fn bind(addr: impl Into<SocketAddr>) -> io::Result<Async<TcpListener>> {
+    Async::<TcpListener>::bind(addr)
+}
+
+/// Create UDP socket bound to the address
+pub fn bind_udp(addr: impl Into<SocketAddr>) -> io::Result<Async<UdpSocket>> {
+    Async::<UdpSocket>::bind(addr)
+}
+
+/// Read entire file on blocking thread
+pub async fn read(path: impl Into<PathBuf>) -> io::Result<Vec<u8>> {
+    let path = path.into();
+
+    spawn_blocking(move || fs::read(path)).await
+}
+
+/// Read entire file as string on blocking thread
+pub async fn read_to_string(path: impl Into<PathBuf>) -> io::Result<String> {
+    let path = path.into();
+
+    spawn_blocking(move || fs::read_to_string(path)).await
+}
+
+/// Write entire file on blocking thread, creating it if it does not exist
+pub async fn write(path: impl Into<PathBuf>, contents: impl Into<Vec<u8>>) -> io::Result<()> {
+    let (path, contents) = (path.into(), contents.into());
+
+    spawn_blocking(move || fs::write(path, contents)).await
+}
+
+/// Check if path exists on blocking thread
+pub async fn exists(path: impl AsRef<Path>) -> bool {
+    let path = path.as_ref().to_path_buf();
+
+    spawn_blocking(move || path.exists()).await
+}
@@ -0,0 +1,76 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Kiosk mode helpers
+//!
+//! Always fullscreen windows, input containment and crash restarting main task.
+//! winit does not expose system shortcut suppression, so it should be configured on OS side.
+
+use std::panic::AssertUnwindSafe;
+
+use futures_lite::{Future, FutureExt};
+use winit::{
+    error::{EventLoopError, ExternalError, OsError},
+    window::{CursorGrabMode, Fullscreen, Window, WindowAttributes, WindowLevel},
+};
+
+use crate::{build_window, executor::with_eventloop_target, spawn_local_ui_task};
+
+/// Create borderless fullscreen window on every available monitors
+///
+/// `attributes` is called once per monitor to create base configuration
+pub fn build_windows(attributes: impl Fn() -> WindowAttributes) -> Result<Vec<Window>, OsError> {
+    let monitors = with_eventloop_target(|target| target.available_monitors().collect::<Vec<_>>());
+
+    monitors
+        .into_iter()
+        .map(|monitor| {
+            build_window(
+                attributes()
+                    .with_decorations(false)
+                    .with_window_level(WindowLevel::AlwaysOnTop)
+                    .with_fullscreen(Some(Fullscreen::Borderless(Some(monitor)))),
+            )
+        })
+        .collect()
+}
+
+/// Confine cursor into window and hide it
+///
+/// Falls back to [`CursorGrabMode::Locked`] on platforms not supporting [`CursorGrabMode::Confined`]
+pub fn confine_cursor(window: &Window) -> Result<(), ExternalError> {
+    window
+        .set_cursor_grab(CursorGrabMode::Confined)
+        .or_else(|_| window.set_cursor_grab(CursorGrabMode::Locked))?;
+
+    window.set_cursor_visible(false);
+    Ok(())
+}
+
+/// Run main task created by `main`, restarting it every time it panics
+///
+/// Completes once main task finishes without panic
+pub async fn supervise<F, Fut>(mut main: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()> + 'static,
+{
+    while spawn_local_ui_task(AssertUnwindSafe(main()).catch_unwind())
+        .await
+        .is_err()
+    {}
+}
+
+/// Entrypoint for kiosk runtime
+///
+/// See [`supervise`]
+pub fn run<F, Fut>(main: F) -> Result<(), EventLoopError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()> + 'static,
+{
+    crate::run(supervise(main))
+}
@@ -11,24 +11,92 @@
 //! 2. Zero cost event dispatching
 //! 3. Spawn ui tasks anywhere. Tasks run in eventloop's thread concurrently
 
-use executor::{executor_handle, with_eventloop_target};
+use std::borrow::Cow;
+
+use cancel::CancellationToken;
+use executor::{
+    event::ExecutorEvent, executor_handle, with_eventloop_target, Priority, RuntimeError,
+};
 use futures_lite::Future;
 use task::Task;
 
+/// Declare function returning static [`EventSource`](crate::event::EventSource) with given event type
+///
+/// ```ignore
+/// winit_runtime::define_event!(
+///     /// Emitted when document is saved
+///     pub saved: &Path
+/// );
+///
+/// winit_runtime::emit!(saved(), &path);
+/// ```
+#[macro_export]
+macro_rules! define_event {
+    ($(#[$meta: meta])* $vis: vis $name: ident: $($ty: tt)*) => {
+        $(#[$meta])*
+        $vis fn $name() -> &'static $crate::EventSource!($($ty)*) {
+            static SOURCE: $crate::EventSource!($($ty)*) = $crate::event::EventSource::new();
+
+            &SOURCE
+        }
+    };
+}
+
+pub mod a11y;
+#[cfg(feature = "accesskit")]
+pub mod accesskit;
+#[cfg(target_os = "android")]
+pub mod android;
+pub mod animation;
+pub mod app;
+pub mod bench;
+pub mod bus;
+pub mod cancel;
+#[cfg(feature = "softbuffer")]
+pub mod canvas;
+#[cfg(feature = "arboard")]
+pub mod clipboard;
+pub mod config;
+#[cfg(feature = "rfd")]
+pub mod dialogs;
+#[cfg(feature = "egui-winit")]
+pub mod egui;
+pub mod event;
 pub mod executor;
+#[cfg(feature = "gilrs")]
+pub mod gamepad;
+#[cfg(feature = "wgpu")]
+pub mod gfx;
+pub mod input;
+#[cfg(all(feature = "async-io", not(target_arch = "wasm32")))]
+pub mod io;
+pub mod kiosk;
+pub mod lifecycle;
+#[cfg(target_os = "macos")]
+pub mod macos;
+pub mod prelude;
+pub mod record;
+pub mod render;
+pub mod scale_factor;
+pub mod sync;
+pub mod test;
 pub mod timer;
+#[cfg(feature = "touch")]
+pub mod touch;
+pub mod window;
 
 pub use async_task as task;
 use winit::{
     error::OsError,
-    event::{DeviceEvent, DeviceId, WindowEvent},
-    window::{Window, WindowBuilder, WindowId},
+    event::{Event, StartCause, WindowEvent},
+    window::{Window, WindowAttributes, WindowId},
 };
 
 /// Spawn and run new task, running on runtime thread
 /// 
 /// See [`ExecutorHandle::spawn`]
 #[inline]
+#[track_caller]
 pub fn spawn_ui_task<Fut>(fut: Fut) -> Task<Fut::Output>
 where
     Fut: Future + Send + 'static,
@@ -37,10 +105,40 @@ where
     executor_handle().spawn(fut)
 }
 
+/// Spawn and run new task, running on runtime thread. Fails if runtime is not started or shut down.
+/// 
+/// See [`ExecutorHandle::try_spawn`]
+#[inline]
+#[track_caller]
+pub fn try_spawn_ui_task<Fut>(fut: Fut) -> Result<Task<Fut::Output>, RuntimeError>
+where
+    Fut: Future + Send + 'static,
+    Fut::Output: Send,
+{
+    executor::try_executor_handle()?.try_spawn(fut)
+}
+
+/// Spawn and run new task until it completes or `token` is cancelled, running on runtime thread
+/// 
+/// Task outputs [`None`] if cancelled. See [`CancellationToken::run_until_cancelled`]
+#[inline]
+#[track_caller]
+pub fn spawn_ui_task_with_token<Fut>(
+    token: CancellationToken,
+    fut: Fut,
+) -> Task<Option<Fut::Output>>
+where
+    Fut: Future + Send + 'static,
+    Fut::Output: Send,
+{
+    executor_handle().spawn(async move { token.run_until_cancelled(fut).await })
+}
+
 /// Spawn and run new task, on runtime thread
 /// 
 /// See [`ExecutorHandle::spawn_local`]
 #[inline]
+#[track_caller]
 pub fn spawn_local_ui_task<Fut>(fut: Fut) -> Task<Fut::Output>
 where
     Fut: Future + 'static,
@@ -49,43 +147,204 @@ where
     executor_handle().spawn_local(fut)
 }
 
-/// Exit event loop with exit code
+/// Spawn and run new task, on runtime thread. Fails if it is not called on runtime thread.
+/// 
+/// See [`ExecutorHandle::try_spawn_local`]
+#[inline]
+#[track_caller]
+pub fn try_spawn_local_ui_task<Fut>(fut: Fut) -> Result<Task<Fut::Output>, RuntimeError>
+where
+    Fut: Future + 'static,
+    Fut::Output: 'static,
+{
+    executor::try_executor_handle()?.try_spawn_local(fut)
+}
+
+/// Spawn and run new task with given [`Priority`], running on runtime thread
+/// 
+/// See [`ExecutorHandle::spawn_with_priority`]
+#[inline]
+#[track_caller]
+pub fn spawn_ui_task_with_priority<Fut>(priority: Priority, fut: Fut) -> Task<Fut::Output>
+where
+    Fut: Future + Send + 'static,
+    Fut::Output: Send,
+{
+    executor_handle().spawn_with_priority(priority, fut)
+}
+
+/// Spawn and run new task with given [`Priority`], on runtime thread
+/// 
+/// See [`ExecutorHandle::spawn_local_with_priority`]
+#[inline]
+#[track_caller]
+pub fn spawn_local_ui_task_with_priority<Fut>(priority: Priority, fut: Fut) -> Task<Fut::Output>
+where
+    Fut: Future + 'static,
+    Fut::Output: 'static,
+{
+    executor_handle().spawn_local_with_priority(priority, fut)
+}
+
+/// Spawn and run new deadline task, running on runtime thread
+/// 
+/// See [`ExecutorHandle::spawn_before`]
+#[inline]
+#[track_caller]
+pub fn spawn_ui_task_before<Fut>(deadline: instant::Instant, fut: Fut) -> Task<Fut::Output>
+where
+    Fut: Future + Send + 'static,
+    Fut::Output: Send,
+{
+    executor_handle().spawn_before(deadline, fut)
+}
+
+/// Spawn and run new deadline task, on runtime thread
+/// 
+/// See [`ExecutorHandle::spawn_local_before`]
+#[inline]
+#[track_caller]
+pub fn spawn_local_ui_task_before<Fut>(deadline: instant::Instant, fut: Fut) -> Task<Fut::Output>
+where
+    Fut: Future + 'static,
+    Fut::Output: 'static,
+{
+    executor_handle().spawn_local_before(deadline, fut)
+}
+
+/// Spawn and run new named task, running on runtime thread
+/// 
+/// See [`ExecutorHandle::spawn_named`]
+#[inline]
+#[track_caller]
+pub fn spawn_ui_task_named<Fut>(name: impl Into<Cow<'static, str>>, fut: Fut) -> Task<Fut::Output>
+where
+    Fut: Future + Send + 'static,
+    Fut::Output: Send,
+{
+    executor_handle().spawn_named(name, fut)
+}
+
+/// Spawn and run new named task, on runtime thread
+/// 
+/// See [`ExecutorHandle::spawn_local_named`]
+#[inline]
+#[track_caller]
+pub fn spawn_local_ui_task_named<Fut>(name: impl Into<Cow<'static, str>>, fut: Fut) -> Task<Fut::Output>
+where
+    Fut: Future + 'static,
+    Fut::Output: 'static,
+{
+    executor_handle().spawn_local_named(name, fut)
+}
+
+/// Run blocking function on a shared thread pool, without blocking event loop
+///
+/// Pool grows on demand up to a bounded number of threads, idle threads exit after a while.
+/// Function is not started if returned [`Task`] is dropped before it is picked up by a thread.
+/// Not available on platforms without thread support like wasm.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn_blocking<F, R>(f: F) -> Task<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    blocking::unblock(f)
+}
+
+/// Exit event loop
+///
+/// See [`ExecutorHandle::exit_with`](executor::handle::ExecutorHandle::exit_with)
 #[inline]
 pub async fn exit() -> ! {
     executor_handle().exit().await
 }
 
-macro_rules! define_event {
-    (pub $name: ident: $($ty: tt)*) => {
-        pub fn $name() -> &'static event_source::EventSource!($($ty)*) {
-            static SOURCE: event_source::EventSource!($($ty)*) = event_source::EventSource::new();
+/// Exit event loop with exit code
+///
+/// See [`ExecutorHandle::exit_with`](executor::handle::ExecutorHandle::exit_with)
+#[inline]
+pub async fn exit_with(code: i32) -> ! {
+    executor_handle().exit_with(code).await
+}
 
-            &SOURCE
-        }
-    };
+/// Request exiting event loop with exit code, returns if it is cancelled
+///
+/// See [`ExecutorHandle::request_exit`](executor::handle::ExecutorHandle::request_exit)
+#[inline]
+pub async fn request_exit(code: i32) -> executor::ExitCancelled {
+    executor_handle().request_exit(code).await
 }
 
+define_event!(
+    /// Every event verbatim, emitted before runtime dispatches it to other sources
+    ///
+    /// For integrations like egui or imgui platform backends, which feed their own state with every event.
+    pub raw_event: &Event<ExecutorEvent>
+);
+
 define_event!(pub window: (WindowId, &mut WindowEvent));
 
-define_event!(pub device: (DeviceId, &DeviceEvent));
+#[cfg(feature = "device-events")]
+define_event!(pub device: (winit::event::DeviceId, &winit::event::DeviceEvent));
+
+#[cfg(feature = "device-events")]
+define_event!(
+    /// Sum of [`DeviceEvent::MouseMotion`](winit::event::DeviceEvent::MouseMotion) deltas of every device in an event loop iteration
+    ///
+    /// Emitted once per iteration before event loop waits, only if there was any motion.
+    pub raw_motion_frame: (f64, f64)
+);
 
 define_event!(pub resumed: ());
 
 define_event!(pub suspended: ());
 
-/// Create new window using given [`WindowBuilder`]
+define_event!(
+    /// New event loop iteration started with its [`StartCause`]
+    pub new_events: StartCause
+);
+
+define_event!(
+    /// Exit is requested with [`exit`], [`exit_with`] or [`request_exit`], which listeners can cancel
+    pub exit_requested: &mut executor::ExitRequest
+);
+
+define_event!(
+    /// Event loop is exiting, last chance to flush application state
+    pub exiting: ()
+);
+
+define_event!(
+    /// System is low on memory, caches should be released. Emitted on mobile platforms only.
+    pub memory_warning: ()
+);
+
+/// Create new window using given [`WindowAttributes`]
 /// 
 /// also see [`with_eventloop_target`]
-pub fn build_window(builder: WindowBuilder) -> Result<Window, OsError> {
-    with_eventloop_target(move |target| builder.build(target))
+pub fn build_window(attributes: WindowAttributes) -> Result<Window, OsError> {
+    let window = with_eventloop_target(move |target| target.create_window(attributes))?;
+    executor_handle().add_window(window.id());
+    executor_handle().set_metrics(window.id(), window::WindowMetrics::of(&window));
+
+    Ok(window)
 }
 
 #[inline]
 /// Create new window with default configuration
 /// 
-/// This function shortcut for [`build_window(WindowBuilder::new())`]
+/// This function shortcut for [`build_window(Window::default_attributes())`]
 pub fn create_window() -> Result<Window, OsError> {
-    build_window(WindowBuilder::new())
+    build_window(Window::default_attributes())
 }
 
-pub use executor::run;
+pub use winit::raw_window_handle;
+
+pub use executor::{run, run_headless_timers, run_result, run_with, run_with_setup, RuntimeBuilder};
+
+#[cfg(target_arch = "wasm32")]
+pub use executor::run_web;
+
+#[cfg(not(any(target_arch = "wasm32", target_os = "ios")))]
+pub use executor::run_on_demand;
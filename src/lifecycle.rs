@@ -0,0 +1,187 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Application lifecycle helpers
+//!
+//! On mobile and web, rendering surfaces are only valid between [`resumed`] and [`suspended`] events.
+//!
+//! Startup milestones like [`first_resumed`] can be awaited any time, completing immediately once reached.
+
+use std::collections::HashMap;
+
+use futures_lite::Future;
+use instant::Instant;
+use parking_lot::Mutex;
+use winit::{
+    raw_window_handle::{
+        DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, WindowHandle,
+    },
+    window::{Window, WindowId},
+};
+
+use crate::{executor::executor_handle, resumed, suspended, sync::Promise};
+
+/// Wait until application is resumed, completes immediately if it is already resumed
+pub async fn wait_resumed() {
+    if !executor_handle().is_resumed() {
+        resumed().once(|_, _| Some(())).await;
+    }
+}
+
+/// Wait until application is suspended, completes immediately if it is already suspended
+pub async fn wait_suspended() {
+    if executor_handle().is_resumed() {
+        suspended().once(|_, _| Some(())).await;
+    }
+}
+
+/// Create resource on every resume and destroy it on every suspend
+///
+/// `destroy` runs on runtime thread before control returns to event loop from the [`suspended`] event.
+/// If application is suspended while `create` is running, created resource is destroyed immediately.
+/// ```ignore
+/// lifecycle::surface_scope(|| create_renderer(), |renderer| renderer.destroy()).await;
+/// ```
+pub async fn surface_scope<T, C, Fut, D>(mut create: C, mut destroy: D) -> !
+where
+    C: FnMut() -> Fut,
+    Fut: Future<Output = T>,
+    D: FnMut(T),
+{
+    loop {
+        wait_resumed().await;
+        let resource = create().await;
+
+        wait_suspended().await;
+        destroy(resource);
+    }
+}
+
+/// Wait until application is resumed and get window and display handles of the window
+///
+/// See [`SurfaceHandles`]
+pub async fn surface_handles(window: &Window) -> SurfaceHandles<'_> {
+    wait_resumed().await;
+
+    SurfaceHandles {
+        window,
+        generation: executor_handle().resume_generation(),
+    }
+}
+
+/// Raw window and display handles of a window, valid until application is suspended
+///
+/// Once application is suspended, handles are unavailable even after resumed again, so stale surfaces cannot be created.
+/// Handles borrowed from it must not be held across await points.
+#[derive(Debug, Clone, Copy)]
+pub struct SurfaceHandles<'a> {
+    window: &'a Window,
+    generation: u64,
+}
+
+impl<'a> SurfaceHandles<'a> {
+    pub const fn window(&self) -> &'a Window {
+        self.window
+    }
+
+    /// Check if application is not suspended since handles are acquired
+    pub fn is_valid(&self) -> bool {
+        let handle = executor_handle();
+
+        handle.is_resumed() && handle.resume_generation() == self.generation
+    }
+
+    /// Wait until handles become invalid
+    pub async fn invalidated(&self) {
+        if self.is_valid() {
+            suspended().once(|_, _| Some(())).await;
+        }
+    }
+
+    fn check(&self) -> Result<(), HandleError> {
+        if self.is_valid() {
+            Ok(())
+        } else {
+            Err(HandleError::Unavailable)
+        }
+    }
+}
+
+impl HasWindowHandle for SurfaceHandles<'_> {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        self.check()?;
+        self.window.window_handle()
+    }
+}
+
+impl HasDisplayHandle for SurfaceHandles<'_> {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        self.check()?;
+        self.window.display_handle()
+    }
+}
+
+/// Wait until application is resumed first time, returns when it was resumed
+pub async fn first_resumed() -> Instant {
+    *executor_handle().milestones().resumed.wait().await
+}
+
+/// Wait until first window is created by runtime, returns the window and when it was created
+pub async fn first_window_created() -> (WindowId, Instant) {
+    *executor_handle().milestones().window.wait().await
+}
+
+/// Wait until first redraw of the window is dispatched, returns when it was dispatched
+///
+/// Returns [`None`] if window is destroyed before or is not created by runtime.
+pub async fn first_frame_presented(window_id: WindowId) -> Option<Instant> {
+    let handle = executor_handle();
+
+    let frame = {
+        let mut frames = handle.milestones().frames.lock();
+
+        match frames.get(&window_id) {
+            Some(frame) => frame.clone(),
+            None if handle.has_window(window_id) => frames.entry(window_id).or_default().clone(),
+            None => return None,
+        }
+    };
+
+    *frame.wait().await
+}
+
+/// Startup milestones of a runtime
+#[derive(Debug, Default)]
+pub(crate) struct Milestones {
+    resumed: Promise<Instant>,
+    window: Promise<(WindowId, Instant)>,
+    frames: Mutex<HashMap<WindowId, Promise<Option<Instant>>>>,
+}
+
+impl Milestones {
+    pub fn resumed(&self, now: Instant) {
+        let _ = self.resumed.complete(now);
+    }
+
+    pub fn window_created(&self, window_id: WindowId, now: Instant) {
+        let _ = self.window.complete((window_id, now));
+    }
+
+    pub fn frame_presented(&self, window_id: WindowId, now: Instant) {
+        let _ = self
+            .frames
+            .lock()
+            .entry(window_id)
+            .or_default()
+            .complete(Some(now));
+    }
+
+    pub fn window_destroyed(&self, window_id: WindowId) {
+        if let Some(frame) = self.frames.lock().remove(&window_id) {
+            let _ = frame.complete(None);
+        }
+    }
+}
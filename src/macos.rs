@@ -0,0 +1,60 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! macOS application helpers
+//!
+//! Activation policy and default menu can only be set before event loop is built, use [`configure`] with [`run_with`].
+//! winit does not forward application reopen (dock icon click) events yet, so they are not exposed.
+//!
+//! [`run_with`]: crate::run_with
+
+use winit::{
+    event_loop::EventLoopBuilder,
+    platform::macos::{ActiveEventLoopExtMacOS, EventLoopBuilderExtMacOS},
+};
+
+pub use winit::platform::macos::ActivationPolicy;
+
+use crate::executor::{event::ExecutorEvent, with_eventloop_target};
+
+/// Create [`EventLoopBuilder`] configurator setting activation policy and default menu
+///
+/// ```ignore
+/// winit_runtime::run_with(
+///     macos::configure(ActivationPolicy::Accessory, false),
+///     async { ... },
+/// )
+/// ```
+pub fn configure(
+    activation_policy: ActivationPolicy,
+    default_menu: bool,
+) -> impl FnOnce(&mut EventLoopBuilder<ExecutorEvent>) {
+    move |builder| {
+        builder
+            .with_activation_policy(activation_policy)
+            .with_default_menu(default_menu);
+    }
+}
+
+/// Hide the entire application
+pub fn hide_application() {
+    with_eventloop_target(|target| target.hide_application())
+}
+
+/// Hide the other applications
+pub fn hide_other_applications() {
+    with_eventloop_target(|target| target.hide_other_applications())
+}
+
+/// Set whether the system can automatically organize windows into tabs
+pub fn set_allows_automatic_window_tabbing(enabled: bool) {
+    with_eventloop_target(|target| target.set_allows_automatic_window_tabbing(enabled))
+}
+
+/// Check whether the system can automatically organize windows into tabs
+pub fn allows_automatic_window_tabbing() -> bool {
+    with_eventloop_target(|target| target.allows_automatic_window_tabbing())
+}
@@ -0,0 +1,32 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Commonly used items
+//!
+//! ```ignore
+//! use winit_runtime::prelude::*;
+//! ```
+
+pub use crate::{
+    app::App,
+    build_window,
+    cancel::CancellationToken,
+    create_window, emit,
+    event::{ControlFlow, EventSource},
+    executor::{executor_handle, with_eventloop_target},
+    exit, exit_requested, resumed, spawn_local_ui_task, spawn_ui_task, suspended,
+    task::Task,
+    timer::{wait, wait_deadline, wait_until, TimerHandle},
+    window,
+};
+
+#[cfg(feature = "device-events")]
+pub use crate::device;
+
+pub use winit::{
+    event::{DeviceEvent, WindowEvent},
+    window::{Window, WindowAttributes, WindowId},
+};
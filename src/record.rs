@@ -0,0 +1,583 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Event recording and replay
+//!
+//! While recording, every dispatched window and device event is written to a file with its timestamp, one event per line.
+//! Recorded file can be replayed later with original timing for reproducing input bugs deterministically.
+//!
+//! Some events are not recorded, as winit does not allow constructing them for replay:
+//! 1. [`WindowEvent::KeyboardInput`], [`KeyEvent`](winit::event::KeyEvent) has private platform specific fields. Text input through [`WindowEvent::Ime`] is recorded.
+//! 2. [`WindowEvent::ScaleFactorChanged`], its [`InnerSizeWriter`](winit::event::InnerSizeWriter) has no public constructor.
+//! 3. [`WindowEvent::ActivationTokenDone`], its [`AsyncRequestSerial`](winit::event_loop::AsyncRequestSerial) has no public constructor.
+//! 4. [`DeviceEvent::Key`], as [`KeyCode`](winit::keyboard::KeyCode) has no text representation to decode from.
+
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use instant::{Duration, Instant};
+use parking_lot::{const_mutex, Mutex};
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    event::{
+        DeviceEvent, ElementState, Ime, MouseButton, MouseScrollDelta, Touch, TouchPhase,
+        WindowEvent,
+    },
+    keyboard::ModifiersState,
+    window::{Theme, WindowId},
+};
+
+use crate::{
+    executor::inject_window_event,
+    test::device_id,
+    timer::{self, wait_until},
+};
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+static RECORDER: Mutex<Option<Recorder>> = const_mutex(None);
+
+#[derive(Debug)]
+struct Recorder {
+    writer: BufWriter<File>,
+    start: Instant,
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+    fn write(&mut self, kind: &str, event: &str) {
+        if self.error.is_some() {
+            return;
+        }
+
+        if let Err(err) = writeln!(
+            self.writer,
+            "{} {kind} {event}",
+            timer::now()
+                .saturating_duration_since(self.start)
+                .as_micros()
+        ) {
+            self.error = Some(err);
+        }
+    }
+}
+
+/// Start recording events into file at given path
+///
+/// Previous recording is stopped and flushed. Timestamps are taken from runtime clock, so runtime must be running.
+pub fn start(path: impl AsRef<Path>) -> io::Result<()> {
+    let recorder = Recorder {
+        writer: BufWriter::new(File::create(path)?),
+        start: timer::now(),
+        error: None,
+    };
+
+    let previous = RECORDER.lock().replace(recorder);
+    RECORDING.store(true, Ordering::Release);
+
+    match previous {
+        Some(previous) => finish(previous),
+        None => Ok(()),
+    }
+}
+
+/// Stop recording and flush recorded events
+///
+/// Returns first error occurred while recording
+pub fn stop() -> io::Result<()> {
+    RECORDING.store(false, Ordering::Release);
+
+    match RECORDER.lock().take() {
+        Some(recorder) => finish(recorder),
+        None => Ok(()),
+    }
+}
+
+/// Check if events are being recorded
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Acquire)
+}
+
+fn finish(mut recorder: Recorder) -> io::Result<()> {
+    match recorder.error.take() {
+        Some(err) => Err(err),
+        None => recorder.writer.flush(),
+    }
+}
+
+pub(crate) fn record_window_event(window_id: WindowId, event: &WindowEvent) {
+    if !is_recording() {
+        return;
+    }
+
+    if let Some(event) = encode_window_event(event) {
+        if let Some(ref mut recorder) = *RECORDER.lock() {
+            recorder.write(&format!("w {}", u64::from(window_id)), &event);
+        }
+    }
+}
+
+#[cfg(feature = "device-events")]
+pub(crate) fn record_device_event(event: &DeviceEvent) {
+    if !is_recording() {
+        return;
+    }
+
+    if let Some(event) = encode_device_event(event) {
+        if let Some(ref mut recorder) = *RECORDER.lock() {
+            recorder.write("d", &event);
+        }
+    }
+}
+
+/// Replay recorded file with original timing, using runtime timer
+///
+/// Events are dispatched with recorded [`WindowId`] and dummy device ids.
+/// Schedule follows runtime clock, so replay under [`MockClock`](crate::test::MockClock) advances with the mock clock.
+pub async fn replay(path: impl AsRef<Path>) -> io::Result<()> {
+    let data = fs::read_to_string(path)?;
+    let start = timer::now();
+
+    for line in data.lines().filter(|line| !line.is_empty()) {
+        let (time, event) = decode_line(line).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid recorded event: {line}"),
+            )
+        })?;
+
+        let deadline = start + time;
+        if deadline > timer::now() {
+            wait_until(deadline).await;
+        }
+
+        match event {
+            RecordedEvent::Window(window_id, event) => inject_window_event(window_id, event),
+            #[cfg(feature = "device-events")]
+            RecordedEvent::Device(event) => {
+                crate::executor::inject_device_event(device_id(), event)
+            }
+
+            // Device events are skipped if they are not enabled
+            #[cfg(not(feature = "device-events"))]
+            RecordedEvent::Device(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+enum RecordedEvent {
+    Window(WindowId, WindowEvent),
+    #[cfg_attr(not(feature = "device-events"), allow(dead_code))]
+    Device(DeviceEvent),
+}
+
+fn decode_line(line: &str) -> Option<(Duration, RecordedEvent)> {
+    let mut tokens = line.split_whitespace();
+
+    let time = Duration::from_micros(tokens.next()?.parse().ok()?);
+    let event = match tokens.next()? {
+        "w" => {
+            let window_id = WindowId::from(tokens.next()?.parse::<u64>().ok()?);
+            RecordedEvent::Window(window_id, decode_window_event(tokens)?)
+        }
+
+        "d" => RecordedEvent::Device(decode_device_event(tokens)?),
+
+        _ => return None,
+    };
+
+    Some((time, event))
+}
+
+fn encode_window_event(event: &WindowEvent) -> Option<String> {
+    Some(match event {
+        WindowEvent::Resized(size) => format!("resized {} {}", size.width, size.height),
+        WindowEvent::Moved(position) => format!("moved {} {}", position.x, position.y),
+        WindowEvent::CloseRequested => "close_requested".into(),
+        WindowEvent::Destroyed => "destroyed".into(),
+        WindowEvent::DroppedFile(path) => format!("dropped_file {}", escape(path.to_str()?)),
+        WindowEvent::HoveredFile(path) => format!("hovered_file {}", escape(path.to_str()?)),
+        WindowEvent::HoveredFileCancelled => "hovered_file_cancelled".into(),
+        WindowEvent::Focused(focused) => format!("focused {focused}"),
+        WindowEvent::ModifiersChanged(modifiers) => {
+            format!("modifiers_changed {}", modifiers.state().bits())
+        }
+        WindowEvent::CursorMoved { position, .. } => {
+            format!("cursor_moved {} {}", position.x, position.y)
+        }
+        WindowEvent::CursorEntered { .. } => "cursor_entered".into(),
+        WindowEvent::CursorLeft { .. } => "cursor_left".into(),
+        WindowEvent::MouseWheel { delta, phase, .. } => {
+            format!(
+                "mouse_wheel {} {}",
+                encode_scroll_delta(delta),
+                encode_phase(*phase)
+            )
+        }
+        WindowEvent::MouseInput { state, button, .. } => format!(
+            "mouse_input {} {}",
+            encode_state(*state),
+            encode_button(*button)
+        ),
+        WindowEvent::Touch(touch) => format!(
+            "touch {} {} {} {}",
+            touch.id,
+            encode_phase(touch.phase),
+            touch.location.x,
+            touch.location.y
+        ),
+        WindowEvent::Ime(ime) => format!("ime {}", encode_ime(ime)),
+        WindowEvent::PinchGesture { delta, phase, .. } => {
+            format!("pinch_gesture {delta} {}", encode_phase(*phase))
+        }
+        WindowEvent::PanGesture { delta, phase, .. } => {
+            format!(
+                "pan_gesture {} {} {}",
+                delta.x,
+                delta.y,
+                encode_phase(*phase)
+            )
+        }
+        WindowEvent::DoubleTapGesture { .. } => "double_tap_gesture".into(),
+        WindowEvent::RotationGesture { delta, phase, .. } => {
+            format!("rotation_gesture {delta} {}", encode_phase(*phase))
+        }
+        WindowEvent::TouchpadPressure {
+            pressure, stage, ..
+        } => format!("touchpad_pressure {pressure} {stage}"),
+        WindowEvent::AxisMotion { axis, value, .. } => format!("axis_motion {axis} {value}"),
+        WindowEvent::ThemeChanged(theme) => format!(
+            "theme_changed {}",
+            match theme {
+                Theme::Light => "light",
+                Theme::Dark => "dark",
+            }
+        ),
+        WindowEvent::Occluded(occluded) => format!("occluded {occluded}"),
+        WindowEvent::RedrawRequested => "redraw_requested".into(),
+
+        WindowEvent::KeyboardInput { .. }
+        | WindowEvent::ScaleFactorChanged { .. }
+        | WindowEvent::ActivationTokenDone { .. } => return None,
+    })
+}
+
+fn decode_window_event<'a>(mut tokens: impl Iterator<Item = &'a str>) -> Option<WindowEvent> {
+    let device_id = device_id();
+
+    Some(match tokens.next()? {
+        "resized" => WindowEvent::Resized(PhysicalSize::new(
+            tokens.next()?.parse().ok()?,
+            tokens.next()?.parse().ok()?,
+        )),
+        "moved" => WindowEvent::Moved(PhysicalPosition::new(
+            tokens.next()?.parse().ok()?,
+            tokens.next()?.parse().ok()?,
+        )),
+        "close_requested" => WindowEvent::CloseRequested,
+        "destroyed" => WindowEvent::Destroyed,
+        "dropped_file" => WindowEvent::DroppedFile(PathBuf::from(unescape(tokens.next()?)?)),
+        "hovered_file" => WindowEvent::HoveredFile(PathBuf::from(unescape(tokens.next()?)?)),
+        "hovered_file_cancelled" => WindowEvent::HoveredFileCancelled,
+        "focused" => WindowEvent::Focused(tokens.next()?.parse().ok()?),
+        "modifiers_changed" => WindowEvent::ModifiersChanged(
+            ModifiersState::from_bits_truncate(tokens.next()?.parse().ok()?).into(),
+        ),
+        "cursor_moved" => WindowEvent::CursorMoved {
+            device_id,
+            position: PhysicalPosition::new(
+                tokens.next()?.parse().ok()?,
+                tokens.next()?.parse().ok()?,
+            ),
+        },
+        "cursor_entered" => WindowEvent::CursorEntered { device_id },
+        "cursor_left" => WindowEvent::CursorLeft { device_id },
+        "mouse_wheel" => WindowEvent::MouseWheel {
+            device_id,
+            delta: decode_scroll_delta(&mut tokens)?,
+            phase: decode_phase(tokens.next()?)?,
+        },
+        "mouse_input" => WindowEvent::MouseInput {
+            device_id,
+            state: decode_state(tokens.next()?)?,
+            button: decode_button(tokens.next()?)?,
+        },
+        "touch" => WindowEvent::Touch(Touch {
+            device_id,
+            id: tokens.next()?.parse().ok()?,
+            phase: decode_phase(tokens.next()?)?,
+            location: PhysicalPosition::new(
+                tokens.next()?.parse().ok()?,
+                tokens.next()?.parse().ok()?,
+            ),
+            force: None,
+        }),
+        "ime" => WindowEvent::Ime(decode_ime(tokens)?),
+        "pinch_gesture" => WindowEvent::PinchGesture {
+            device_id,
+            delta: tokens.next()?.parse().ok()?,
+            phase: decode_phase(tokens.next()?)?,
+        },
+        "pan_gesture" => WindowEvent::PanGesture {
+            device_id,
+            delta: PhysicalPosition::new(
+                tokens.next()?.parse().ok()?,
+                tokens.next()?.parse().ok()?,
+            ),
+            phase: decode_phase(tokens.next()?)?,
+        },
+        "double_tap_gesture" => WindowEvent::DoubleTapGesture { device_id },
+        "rotation_gesture" => WindowEvent::RotationGesture {
+            device_id,
+            delta: tokens.next()?.parse().ok()?,
+            phase: decode_phase(tokens.next()?)?,
+        },
+        "touchpad_pressure" => WindowEvent::TouchpadPressure {
+            device_id,
+            pressure: tokens.next()?.parse().ok()?,
+            stage: tokens.next()?.parse().ok()?,
+        },
+        "axis_motion" => WindowEvent::AxisMotion {
+            device_id,
+            axis: tokens.next()?.parse().ok()?,
+            value: tokens.next()?.parse().ok()?,
+        },
+        "theme_changed" => WindowEvent::ThemeChanged(match tokens.next()? {
+            "light" => Theme::Light,
+            "dark" => Theme::Dark,
+            _ => return None,
+        }),
+        "occluded" => WindowEvent::Occluded(tokens.next()?.parse().ok()?),
+        "redraw_requested" => WindowEvent::RedrawRequested,
+
+        _ => return None,
+    })
+}
+
+#[cfg(feature = "device-events")]
+fn encode_device_event(event: &DeviceEvent) -> Option<String> {
+    Some(match event {
+        DeviceEvent::Added => "added".into(),
+        DeviceEvent::Removed => "removed".into(),
+        DeviceEvent::MouseMotion { delta: (x, y) } => format!("mouse_motion {x} {y}"),
+        DeviceEvent::MouseWheel { delta } => {
+            format!("mouse_wheel {}", encode_scroll_delta(delta))
+        }
+        DeviceEvent::Motion { axis, value } => format!("motion {axis} {value}"),
+        DeviceEvent::Button { button, state } => {
+            format!("button {button} {}", encode_state(*state))
+        }
+
+        DeviceEvent::Key(_) => return None,
+    })
+}
+
+fn decode_device_event<'a>(mut tokens: impl Iterator<Item = &'a str>) -> Option<DeviceEvent> {
+    Some(match tokens.next()? {
+        "added" => DeviceEvent::Added,
+        "removed" => DeviceEvent::Removed,
+        "mouse_motion" => DeviceEvent::MouseMotion {
+            delta: (tokens.next()?.parse().ok()?, tokens.next()?.parse().ok()?),
+        },
+        "mouse_wheel" => DeviceEvent::MouseWheel {
+            delta: decode_scroll_delta(&mut tokens)?,
+        },
+        "motion" => DeviceEvent::Motion {
+            axis: tokens.next()?.parse().ok()?,
+            value: tokens.next()?.parse().ok()?,
+        },
+        "button" => DeviceEvent::Button {
+            button: tokens.next()?.parse().ok()?,
+            state: decode_state(tokens.next()?)?,
+        },
+
+        _ => return None,
+    })
+}
+
+fn encode_ime(ime: &Ime) -> String {
+    match ime {
+        Ime::Enabled => "enabled".into(),
+        Ime::Preedit(text, Some((start, end))) => format!("preedit {} {start} {end}", escape(text)),
+        Ime::Preedit(text, None) => format!("preedit {}", escape(text)),
+        Ime::Commit(text) => format!("commit {}", escape(text)),
+        Ime::Disabled => "disabled".into(),
+    }
+}
+
+fn decode_ime<'a>(mut tokens: impl Iterator<Item = &'a str>) -> Option<Ime> {
+    Some(match tokens.next()? {
+        "enabled" => Ime::Enabled,
+        "preedit" => {
+            let text = unescape(tokens.next()?)?;
+            let cursor = match (tokens.next(), tokens.next()) {
+                (Some(start), Some(end)) => Some((start.parse().ok()?, end.parse().ok()?)),
+                _ => None,
+            };
+
+            Ime::Preedit(text, cursor)
+        }
+        "commit" => Ime::Commit(unescape(tokens.next()?)?),
+        "disabled" => Ime::Disabled,
+
+        _ => return None,
+    })
+}
+
+/// Escape text into single token, percent encoding whitespace and `%`
+fn escape(text: &str) -> String {
+    // Empty text still needs a token
+    if text.is_empty() {
+        return "%".into();
+    }
+
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch == '%' || ch.is_whitespace() {
+            let mut buf = [0; 4];
+            for byte in ch.encode_utf8(&mut buf).bytes() {
+                escaped.push_str(&format!("%{byte:02X}"));
+            }
+        } else {
+            escaped.push(ch);
+        }
+    }
+
+    escaped
+}
+
+fn unescape(token: &str) -> Option<String> {
+    if token == "%" {
+        return Some(String::new());
+    }
+
+    let mut bytes = Vec::with_capacity(token.len());
+    let mut iter = token.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+fn encode_scroll_delta(delta: &MouseScrollDelta) -> String {
+    match delta {
+        MouseScrollDelta::LineDelta(x, y) => format!("line {x} {y}"),
+        MouseScrollDelta::PixelDelta(position) => format!("pixel {} {}", position.x, position.y),
+    }
+}
+
+fn decode_scroll_delta<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Option<MouseScrollDelta> {
+    Some(match tokens.next()? {
+        "line" => {
+            MouseScrollDelta::LineDelta(tokens.next()?.parse().ok()?, tokens.next()?.parse().ok()?)
+        }
+        "pixel" => MouseScrollDelta::PixelDelta(PhysicalPosition::new(
+            tokens.next()?.parse().ok()?,
+            tokens.next()?.parse().ok()?,
+        )),
+
+        _ => return None,
+    })
+}
+
+fn encode_phase(phase: TouchPhase) -> &'static str {
+    match phase {
+        TouchPhase::Started => "started",
+        TouchPhase::Moved => "moved",
+        TouchPhase::Ended => "ended",
+        TouchPhase::Cancelled => "cancelled",
+    }
+}
+
+fn decode_phase(phase: &str) -> Option<TouchPhase> {
+    Some(match phase {
+        "started" => TouchPhase::Started,
+        "moved" => TouchPhase::Moved,
+        "ended" => TouchPhase::Ended,
+        "cancelled" => TouchPhase::Cancelled,
+
+        _ => return None,
+    })
+}
+
+fn encode_state(state: ElementState) -> &'static str {
+    match state {
+        ElementState::Pressed => "pressed",
+        ElementState::Released => "released",
+    }
+}
+
+fn decode_state(state: &str) -> Option<ElementState> {
+    Some(match state {
+        "pressed" => ElementState::Pressed,
+        "released" => ElementState::Released,
+
+        _ => return None,
+    })
+}
+
+fn encode_button(button: MouseButton) -> String {
+    match button {
+        MouseButton::Left => "left".into(),
+        MouseButton::Right => "right".into(),
+        MouseButton::Middle => "middle".into(),
+        MouseButton::Back => "back".into(),
+        MouseButton::Forward => "forward".into(),
+        MouseButton::Other(button) => button.to_string(),
+    }
+}
+
+fn decode_button(button: &str) -> Option<MouseButton> {
+    Some(match button {
+        "left" => MouseButton::Left,
+        "right" => MouseButton::Right,
+        "middle" => MouseButton::Middle,
+        "back" => MouseButton::Back,
+        "forward" => MouseButton::Forward,
+
+        button => MouseButton::Other(button.parse().ok()?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use winit::event::WindowEvent;
+
+    use super::{decode_window_event, encode_window_event};
+
+    fn round_trip(event: WindowEvent) {
+        let encoded = encode_window_event(&event).unwrap();
+        assert!(!encoded.contains(['\t', '\n']), "{encoded:?}");
+
+        assert_eq!(
+            decode_window_event(encoded.split_whitespace()),
+            Some(event),
+            "{encoded:?}"
+        );
+    }
+
+    #[test]
+    fn file_paths_with_whitespace() {
+        for path in ["a  b\tc\nd", " leading", "trailing ", "100%", ""] {
+            round_trip(WindowEvent::DroppedFile(PathBuf::from(path)));
+            round_trip(WindowEvent::HoveredFile(PathBuf::from(path)));
+        }
+    }
+}
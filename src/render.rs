@@ -0,0 +1,230 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Per-window redraw scheduling for multi-window apps
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Weak},
+    task::{Poll, Waker},
+};
+
+use futures_lite::future;
+use parking_lot::Mutex;
+use winit::{
+    event::WindowEvent,
+    window::{Window, WindowId},
+};
+
+use crate::{spawn_ui_task, task::Task};
+
+/// Redraw scheduler tracking dirty state of each registered window
+///
+/// Redraw requests are coalesced, so window is requested to redraw once until its [`WindowEvent::RedrawRequested`] arrives.
+/// Dirty state is cleared once redraw is dispatched. Marking window dirty while rendering schedules next frame.
+///
+/// ```ignore
+/// let scheduler = RenderScheduler::new();
+/// scheduler.register(&window_a);
+/// scheduler.register(&window_b);
+///
+/// loop {
+///     let (_, window) = scheduler.next().await;
+///     render(&window);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RenderScheduler {
+    state: Arc<Mutex<SchedulerState>>,
+    _task: Task<()>,
+}
+
+impl RenderScheduler {
+    /// Create scheduler with no windows
+    pub fn new() -> Self {
+        let state = Arc::new(Mutex::new(SchedulerState::default()));
+
+        let task = spawn_ui_task({
+            let state = state.clone();
+
+            crate::window().on(move |(window_id, event), _| match event {
+                WindowEvent::RedrawRequested => state.lock().dispatch(window_id),
+
+                WindowEvent::Destroyed => {
+                    state.lock().remove(window_id);
+                }
+
+                _ => {}
+            })
+        });
+
+        Self { state, _task: task }
+    }
+
+    /// Start scheduling redraws of the window. Window is unregistered once destroyed.
+    pub fn register(&self, window: &Arc<Window>) {
+        self.state.lock().windows.insert(
+            window.id(),
+            Slot {
+                window: Arc::downgrade(window),
+                dirty: false,
+                pending: false,
+                waker: None,
+            },
+        );
+    }
+
+    pub fn unregister(&self, window_id: WindowId) {
+        self.state.lock().remove(window_id);
+    }
+
+    /// Request redraw of the window, coalesced with previous requests until it is redrawn
+    ///
+    /// Can be called on any thread.
+    pub fn mark_dirty(&self, window_id: WindowId) {
+        let window = {
+            let mut state = self.state.lock();
+            let Some(slot) = state.windows.get_mut(&window_id) else {
+                return;
+            };
+
+            if std::mem::replace(&mut slot.dirty, true) {
+                return;
+            }
+
+            slot.window.upgrade()
+        };
+
+        if let Some(window) = window {
+            window.request_redraw();
+        }
+    }
+
+    /// Check if redraw of the window is requested but not dispatched yet
+    pub fn is_dirty(&self, window_id: WindowId) -> bool {
+        self.state
+            .lock()
+            .windows
+            .get(&window_id)
+            .is_some_and(|slot| slot.dirty)
+    }
+
+    /// Wait for next redraw of any registered window, in order they are dispatched
+    ///
+    /// Redraws are queued until taken, so none are missed while rendering.
+    pub async fn next(&self) -> (WindowId, Arc<Window>) {
+        future::poll_fn(|cx| {
+            let mut state = self.state.lock();
+
+            while let Some(window_id) = state.ready.pop_front() {
+                if let Some(window) = state.take(window_id) {
+                    return Poll::Ready((window_id, window));
+                }
+            }
+
+            if !state
+                .waker
+                .as_ref()
+                .is_some_and(|waker| waker.will_wake(cx.waker()))
+            {
+                state.waker = Some(cx.waker().clone());
+            }
+
+            Poll::Pending
+        })
+        .await
+    }
+
+    /// Wait for next redraw of the window
+    ///
+    /// Returns [`None`] if window is not registered or destroyed while waiting.
+    pub async fn frame(&self, window_id: WindowId) -> Option<Arc<Window>> {
+        future::poll_fn(|cx| {
+            let mut state = self.state.lock();
+
+            let Some(slot) = state.windows.get_mut(&window_id) else {
+                return Poll::Ready(None);
+            };
+
+            if !slot.pending {
+                slot.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+
+            state.ready.retain(|id| *id != window_id);
+            Poll::Ready(state.take(window_id))
+        })
+        .await
+    }
+}
+
+impl Default for RenderScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    windows: HashMap<WindowId, Slot>,
+
+    /// Windows with dispatched redraw not taken yet, in order
+    ready: VecDeque<WindowId>,
+    waker: Option<Waker>,
+}
+
+impl SchedulerState {
+    fn dispatch(&mut self, window_id: WindowId) {
+        let Some(slot) = self.windows.get_mut(&window_id) else {
+            return;
+        };
+        slot.dirty = false;
+
+        if std::mem::replace(&mut slot.pending, true) {
+            return;
+        }
+        self.ready.push_back(window_id);
+
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Take pending redraw of the window
+    fn take(&mut self, window_id: WindowId) -> Option<Arc<Window>> {
+        let slot = self.windows.get_mut(&window_id)?;
+
+        if !std::mem::take(&mut slot.pending) {
+            return None;
+        }
+
+        slot.window.upgrade()
+    }
+
+    fn remove(&mut self, window_id: WindowId) {
+        if let Some(mut slot) = self.windows.remove(&window_id) {
+            self.ready.retain(|id| *id != window_id);
+
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Slot {
+    window: Weak<Window>,
+    dirty: bool,
+
+    /// Redraw is dispatched but not taken yet
+    pending: bool,
+    waker: Option<Waker>,
+}
@@ -0,0 +1,108 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Synchronous scale factor change callbacks
+//!
+//! [`InnerSizeWriter`] can only be answered while [`WindowEvent::ScaleFactorChanged`] is being dispatched.
+//! Callbacks registered here are called during dispatch, before listeners of [`window`](crate::window) event source.
+//!
+//! [`WindowEvent::ScaleFactorChanged`]: winit::event::WindowEvent::ScaleFactorChanged
+
+use std::{cell::RefCell, collections::HashMap, marker::PhantomData, rc::Rc};
+
+use winit::{event::InnerSizeWriter, window::WindowId};
+
+use crate::executor::executor_handle;
+
+type Callback = Rc<RefCell<dyn FnMut(f64, &mut InnerSizeWriter)>>;
+
+thread_local! {
+    static CALLBACKS: RefCell<HashMap<WindowId, Vec<(u64, Callback)>>> = RefCell::new(HashMap::new());
+    static NEXT_ID: RefCell<u64> = const { RefCell::new(0) };
+}
+
+/// Register callback called with new scale factor and [`InnerSizeWriter`] when scale factor of the window changes
+///
+/// Callback is unregistered when returned [`ScaleFactorCallback`] is dropped.
+///
+/// # Panics
+/// Panics if called outside of event loop thread
+#[track_caller]
+pub fn on_scale_factor_change(
+    window_id: WindowId,
+    callback: impl FnMut(f64, &mut InnerSizeWriter) + 'static,
+) -> ScaleFactorCallback {
+    executor_handle().assert_runtime_thread("on_scale_factor_change");
+
+    let id = NEXT_ID.with(|next| {
+        let mut next = next.borrow_mut();
+        *next += 1;
+        *next
+    });
+
+    CALLBACKS.with(|callbacks| {
+        callbacks
+            .borrow_mut()
+            .entry(window_id)
+            .or_default()
+            .push((id, Rc::new(RefCell::new(callback))));
+    });
+
+    ScaleFactorCallback {
+        window_id,
+        id,
+        _not_send: PhantomData,
+    }
+}
+
+/// Registration guard of scale factor change callback
+///
+/// Not [`Send`], as callback is registered in thread local storage of runtime thread.
+#[derive(Debug)]
+#[must_use = "Callback is unregistered when dropped"]
+pub struct ScaleFactorCallback {
+    window_id: WindowId,
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ScaleFactorCallback {
+    fn drop(&mut self) {
+        // Ignore if thread local storage is already destroyed
+        let _ = CALLBACKS.try_with(|callbacks| {
+            let mut callbacks = callbacks.borrow_mut();
+
+            if let Some(list) = callbacks.get_mut(&self.window_id) {
+                list.retain(|(id, _)| *id != self.id);
+
+                if list.is_empty() {
+                    callbacks.remove(&self.window_id);
+                }
+            }
+        });
+    }
+}
+
+/// Call registered callbacks of the window
+pub(crate) fn dispatch(window_id: WindowId, scale_factor: f64, writer: &mut InnerSizeWriter) {
+    // Snapshot callbacks so callbacks can register or unregister others
+    let Some(list) = CALLBACKS.with(|callbacks| callbacks.borrow().get(&window_id).cloned()) else {
+        return;
+    };
+
+    for (id, callback) in list {
+        let registered = CALLBACKS.with(|callbacks| {
+            callbacks
+                .borrow()
+                .get(&window_id)
+                .is_some_and(|list| list.iter().any(|(other, _)| *other == id))
+        });
+
+        if registered {
+            (callback.borrow_mut())(scale_factor, writer);
+        }
+    }
+}
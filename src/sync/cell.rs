@@ -0,0 +1,174 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    cell::RefCell,
+    fmt::{self, Debug},
+    mem::ManuallyDrop,
+    sync::Arc,
+    thread::{self, ThreadId},
+};
+
+use crate::executor::{executor_handle, try_executor_handle, RuntimeError};
+
+use super::oneshot;
+
+/// Shared state bound to runtime thread, for sharing `!Send` ui state across tasks
+///
+/// Cell can be cloned and sent to any thread, but its value is only accessed on runtime thread it is created.
+/// Value is dropped on runtime thread too, or leaked if runtime is shut down already.
+/// ```ignore
+/// let scene = UiCell::new(Rc::new(Scene::new()));
+///
+/// spawn_blocking({
+///     let scene = scene.clone();
+///     move || {
+///         let mesh = load_mesh();
+///         block_on(scene.update_from_any_thread(move |scene| scene.add(mesh)))
+///     }
+/// })
+/// .detach();
+///
+/// scene.with(|scene| scene.render());
+/// ```
+pub struct UiCell<T: 'static> {
+    inner: Arc<Bound<RefCell<T>>>,
+}
+
+impl<T: 'static> UiCell<T> {
+    /// Create cell bound to runtime thread. Panics if called outside of runtime thread.
+    #[track_caller]
+    pub fn new(value: T) -> Self {
+        executor_handle().assert_runtime_thread("UiCell::new");
+
+        Self {
+            inner: Arc::new(Bound::new(RefCell::new(value))),
+        }
+    }
+
+    /// Check if current thread is the thread value is bound to
+    pub fn is_owner_thread(&self) -> bool {
+        self.inner.is_owner_thread()
+    }
+
+    /// Access value on runtime thread
+    ///
+    /// Panics if called outside of runtime thread, or in another [`UiCell::with`] call of same cell.
+    #[track_caller]
+    pub fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        match self.try_with(f) {
+            Ok(output) => output,
+
+            Err(_) => panic!(
+                "Cannot call UiCell::with outside of runtime thread, called on thread `{}`",
+                thread::current().name().unwrap_or("<unnamed>")
+            ),
+        }
+    }
+
+    /// Access value if called on runtime thread, returns [`RuntimeError::NotRuntimeThread`] otherwise
+    ///
+    /// Panics if called in another [`UiCell::with`] call of same cell.
+    pub fn try_with<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, RuntimeError> {
+        match self.inner.get() {
+            Some(value) => Ok(f(&mut value.borrow_mut())),
+            None => Err(RuntimeError::NotRuntimeThread),
+        }
+    }
+
+    /// Access value from any thread by running closure on runtime thread
+    ///
+    /// Runs closure directly if called on runtime thread. Otherwise, it is run in next event loop iteration.
+    /// Returns [`RuntimeError::EventLoopClosed`] if runtime shuts down before running it.
+    pub async fn update_from_any_thread<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut T) -> R + Send + 'static,
+    ) -> Result<R, RuntimeError> {
+        if self.is_owner_thread() {
+            return self.try_with(f);
+        }
+
+        let handle = try_executor_handle()?;
+        let (sender, receiver) = oneshot();
+        let cell = self.clone();
+        handle.post(move || {
+            if let Ok(output) = cell.try_with(f) {
+                let _ = sender.send(output);
+            }
+        });
+
+        receiver.await.map_err(|_| RuntimeError::EventLoopClosed)
+    }
+}
+
+impl<T: 'static> Clone for UiCell<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: 'static> Debug for UiCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UiCell")
+            .field("thread", &self.inner.thread)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Value only accessed and dropped on its owner thread
+struct Bound<T: 'static> {
+    thread: ThreadId,
+    value: ManuallyDrop<T>,
+}
+
+// SAFETY: Value is only accessed on owner thread, and dropped on it or leaked.
+unsafe impl<T: 'static> Send for Bound<T> {}
+unsafe impl<T: 'static> Sync for Bound<T> {}
+
+impl<T: 'static> Bound<T> {
+    fn new(value: T) -> Self {
+        Self {
+            thread: thread::current().id(),
+            value: ManuallyDrop::new(value),
+        }
+    }
+
+    fn is_owner_thread(&self) -> bool {
+        thread::current().id() == self.thread
+    }
+
+    fn get(&self) -> Option<&T> {
+        self.is_owner_thread().then_some(&*self.value)
+    }
+}
+
+impl<T: 'static> Drop for Bound<T> {
+    fn drop(&mut self) {
+        if self.is_owner_thread() {
+            // SAFETY: Value is never used again
+            unsafe { ManuallyDrop::drop(&mut self.value) };
+            return;
+        }
+
+        // Send back to runtime thread, which is owner thread if value is created by current runtime.
+        // Leaked if runtime is shut down, as post drops closure on current thread then.
+        let Ok(handle) = try_executor_handle() else {
+            return;
+        };
+        if handle.is_shut_down() || handle.is_runtime_thread() {
+            return;
+        }
+
+        let bound = Bound {
+            thread: self.thread,
+            // SAFETY: Value is moved and never used again
+            value: ManuallyDrop::new(unsafe { ManuallyDrop::take(&mut self.value) }),
+        };
+        handle.post(move || drop(bound));
+    }
+}
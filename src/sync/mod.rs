@@ -0,0 +1,239 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Synchronization primitives for handing data to ui tasks from any thread
+//!
+//! [`UiCell`] shares `!Send` state of runtime thread with tasks of any thread.
+
+mod cell;
+mod oneshot;
+mod promise;
+
+pub use cell::UiCell;
+pub use oneshot::{oneshot, OneshotReceiver, OneshotSender, RecvError};
+pub use promise::Promise;
+
+use std::{
+    error::Error,
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use concurrent_queue::{ConcurrentQueue, PopError, PushError};
+use futures_lite::Stream;
+use parking_lot::Mutex;
+
+/// Create unbounded channel for sending values from any thread to a ui task
+///
+/// Sending wakes receiving task directly, so event loop is woken once per batch of values.
+/// Receiver returns [`None`] once every senders are dropped and channel is empty.
+pub fn ui_channel<T>() -> (UiSender<T>, UiReceiver<T>) {
+    let shared = Arc::new(Shared {
+        queue: ConcurrentQueue::unbounded(),
+        waker: Mutex::new(None),
+        senders: AtomicUsize::new(1),
+    });
+
+    (
+        UiSender {
+            shared: shared.clone(),
+        },
+        UiReceiver { shared },
+    )
+}
+
+struct Shared<T> {
+    queue: ConcurrentQueue<T>,
+    waker: Mutex<Option<Waker>>,
+    senders: AtomicUsize,
+}
+
+impl<T> Shared<T> {
+    fn wake(&self) {
+        if let Some(waker) = self.waker.lock().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Sending half of [`ui_channel`]
+pub struct UiSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> UiSender<T> {
+    /// Send value to receiver, fails if receiver is dropped
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        match self.shared.queue.push(value) {
+            Ok(_) => {
+                self.shared.wake();
+                Ok(())
+            }
+
+            Err(PushError::Closed(value) | PushError::Full(value)) => Err(SendError(value)),
+        }
+    }
+
+    /// Check if receiver is dropped
+    pub fn is_closed(&self) -> bool {
+        self.shared.queue.is_closed()
+    }
+}
+
+impl<T> Clone for UiSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for UiSender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.queue.close();
+            self.shared.wake();
+        }
+    }
+}
+
+impl<T> Debug for UiSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UiSender")
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Receiving half of [`ui_channel`]
+pub struct UiReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> UiReceiver<T> {
+    /// Receive next value, returns [`None`] if every senders are dropped
+    pub fn recv(&mut self) -> Recv<'_, T> {
+        Recv { receiver: self }
+    }
+
+    /// Receive value without waiting
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        self.shared.queue.pop().map_err(|err| match err {
+            PopError::Empty => TryRecvError::Empty,
+            PopError::Closed => TryRecvError::Closed,
+        })
+    }
+
+    /// Number of values waiting to be received
+    pub fn len(&self) -> usize {
+        self.shared.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shared.queue.is_empty()
+    }
+
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        match self.try_recv() {
+            Ok(value) => return Poll::Ready(Some(value)),
+            Err(TryRecvError::Closed) => return Poll::Ready(None),
+            Err(TryRecvError::Empty) => {}
+        }
+
+        *self.shared.waker.lock() = Some(cx.waker().clone());
+
+        // Check again, value may be sent before waker is registered
+        match self.try_recv() {
+            Ok(value) => Poll::Ready(Some(value)),
+            Err(TryRecvError::Closed) => Poll::Ready(None),
+            Err(TryRecvError::Empty) => Poll::Pending,
+        }
+    }
+}
+
+impl<T> Stream for UiReceiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.get_mut().poll_recv(cx)
+    }
+}
+
+impl<T> Drop for UiReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.queue.close();
+    }
+}
+
+impl<T> Debug for UiReceiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UiReceiver")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Future returned by [`UiReceiver::recv`]
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Recv<'a, T> {
+    receiver: &'a mut UiReceiver<T>,
+}
+
+impl<T> Future for Recv<'_, T> {
+    type Output = Option<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Error returned by [`UiSender::send`] when receiver is dropped, containing unsent value
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SendError(..)")
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sending on a closed channel")
+    }
+}
+
+impl<T> Error for SendError<T> {}
+
+/// Error returned by [`UiReceiver::try_recv`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// Channel is empty
+    Empty,
+
+    /// Channel is empty and every senders are dropped
+    Closed,
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryRecvError::Empty => f.write_str("receiving on an empty channel"),
+            TryRecvError::Closed => f.write_str("receiving on a closed channel"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
@@ -0,0 +1,184 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    error::Error,
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+use parking_lot::Mutex;
+
+/// Create channel for sending single value from any thread to a ui task
+///
+/// Sending wakes receiving task directly. Receiving fails with [`RecvError`] if sender is dropped without sending.
+/// ```ignore
+/// let (sender, receiver) = sync::oneshot();
+///
+/// spawn_ui_task(async move {
+///     lifecycle::wait_resumed().await;
+///     let _ = sender.send(create_window().unwrap());
+/// })
+/// .detach();
+///
+/// let window = receiver.await?;
+/// ```
+pub fn oneshot<T>() -> (OneshotSender<T>, OneshotReceiver<T>) {
+    let shared = Arc::new(Mutex::new(State {
+        value: None,
+        waker: None,
+        closed: false,
+    }));
+
+    (
+        OneshotSender {
+            shared: shared.clone(),
+        },
+        OneshotReceiver { shared },
+    )
+}
+
+struct State<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+
+    /// Other half is dropped, or value is taken
+    closed: bool,
+}
+
+/// Sending half of [`oneshot`]
+pub struct OneshotSender<T> {
+    shared: Arc<Mutex<State<T>>>,
+}
+
+impl<T> OneshotSender<T> {
+    /// Send value to receiver, returns the value back if receiver is dropped
+    pub fn send(self, value: T) -> Result<(), T> {
+        let waker = {
+            let mut state = self.shared.lock();
+            if state.closed {
+                return Err(value);
+            }
+
+            state.value = Some(value);
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+
+        Ok(())
+    }
+
+    /// Check if receiver is dropped
+    pub fn is_closed(&self) -> bool {
+        self.shared.lock().closed
+    }
+}
+
+impl<T> Drop for OneshotSender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut state = self.shared.lock();
+            state.closed = true;
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Debug for OneshotSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OneshotSender")
+            .field("closed", &self.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Receiving half of [`oneshot`], resolves to sent value
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct OneshotReceiver<T> {
+    shared: Arc<Mutex<State<T>>>,
+}
+
+impl<T> OneshotReceiver<T> {
+    /// Receive value without waiting, returns [`None`] if it is not sent yet
+    pub fn try_recv(&mut self) -> Result<Option<T>, RecvError> {
+        let mut state = self.shared.lock();
+
+        match state.value.take() {
+            Some(value) => {
+                state.closed = true;
+                Ok(Some(value))
+            }
+
+            None if state.closed => Err(RecvError),
+
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T> Future for OneshotReceiver<T> {
+    type Output = Result<T, RecvError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock();
+
+        if let Some(value) = state.value.take() {
+            state.closed = true;
+            return Poll::Ready(Ok(value));
+        }
+
+        if state.closed {
+            return Poll::Ready(Err(RecvError));
+        }
+
+        // Waker is cloned only if task is changed
+        if !state
+            .waker
+            .as_ref()
+            .is_some_and(|waker| waker.will_wake(cx.waker()))
+        {
+            state.waker = Some(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for OneshotReceiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.closed = true;
+        state.waker = None;
+    }
+}
+
+impl<T> Debug for OneshotReceiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OneshotReceiver").finish_non_exhaustive()
+    }
+}
+
+/// Error returned by [`OneshotReceiver`] when sender is dropped without sending
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError;
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sender dropped without sending")
+    }
+}
+
+impl Error for RecvError {}
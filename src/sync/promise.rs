@@ -0,0 +1,99 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    fmt::{self, Debug},
+    sync::{Arc, OnceLock},
+};
+
+use futures_intrusive::sync::ManualResetEvent;
+
+/// Value completed once from any thread, awaited by any number of tasks
+///
+/// Cheap to clone, every clones share same value. Waiting does not allocate.
+/// ```ignore
+/// let window = Promise::new();
+///
+/// spawn_ui_task(resumed().on({
+///     let window = window.clone();
+///     move |_, _| {
+///         let _ = window.complete(create_window().unwrap());
+///     }
+/// }))
+/// .detach();
+///
+/// let window = window.wait().await;
+/// ```
+pub struct Promise<T> {
+    inner: Arc<Inner<T>>,
+}
+
+struct Inner<T> {
+    value: OnceLock<T>,
+    event: ManualResetEvent,
+}
+
+impl<T> Promise<T> {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                value: OnceLock::new(),
+                event: ManualResetEvent::new(false),
+            }),
+        }
+    }
+
+    /// Complete with value and wake every waiting tasks, returns the value back if completed already
+    pub fn complete(&self, value: T) -> Result<(), T> {
+        self.inner.value.set(value)?;
+        self.inner.event.set();
+
+        Ok(())
+    }
+
+    /// Get value without waiting
+    pub fn get(&self) -> Option<&T> {
+        self.inner.value.get()
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.get().is_some()
+    }
+
+    /// Wait until completed
+    pub async fn wait(&self) -> &T {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        self.inner.event.wait().await;
+
+        // Event is set only after value is set
+        self.get().unwrap()
+    }
+}
+
+impl<T> Clone for Promise<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Default for Promise<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug> Debug for Promise<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Promise")
+            .field("value", &self.get())
+            .finish()
+    }
+}
@@ -0,0 +1,68 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use instant::{Duration, Instant};
+
+use crate::{executor::try_executor_handle, timer::Clock};
+
+/// Manually advanced [`Clock`] for deterministic tests
+///
+/// ```ignore
+/// let clock: &'static MockClock = Box::leak(Box::new(MockClock::new()));
+///
+/// run_headless_with(RuntimeConfig::new().with_clock(clock), async {
+///     let timer = spawn_ui_task(wait(Duration::from_secs(60)));
+///     clock.advance(Duration::from_secs(60));
+///     timer.await;
+/// });
+/// ```
+#[derive(Debug)]
+pub struct MockClock {
+    start: Instant,
+    elapsed: AtomicU64,
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: AtomicU64::new(0),
+        }
+    }
+
+    /// Total advanced duration
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_micros(self.elapsed.load(Ordering::Acquire))
+    }
+
+    /// Advance clock, firing every timers reaching deadline
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed
+            .fetch_add(duration.as_micros() as u64, Ordering::AcqRel);
+
+        if let Ok(handle) = try_executor_handle() {
+            handle.wake();
+        }
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn is_manual(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Headless runtime for CI and unit tests
+//!
+//! Runs same executor, timer and event sources without display server.
+//! Because there is no real event loop, [`with_eventloop_target`] and window creation is unavailable.
+//! Use [`NullWindow`] as render target instead, which renders into CPU buffer and can be captured for visual regression tests.
+//!
+//! [`with_eventloop_target`]: crate::executor::with_eventloop_target
+
+mod clock;
+mod null_window;
+
+pub use clock::MockClock;
+pub use null_window::{NullWindow, Screenshot};
+
+use futures_lite::Future;
+use winit::{event::DeviceId, window::WindowId};
+
+use crate::{
+    config::RuntimeConfig,
+    executor::{self, headless::Backend},
+};
+
+pub use crate::executor::inject_window_event;
+
+#[cfg(feature = "device-events")]
+pub use crate::executor::inject_device_event;
+
+/// Entrypoint for headless runtime
+///
+/// Like [`crate::run`], only one runtime can run at a time.
+pub fn run_headless(main: impl Future<Output = ()>) {
+    run_headless_with(RuntimeConfig::new(), main)
+}
+
+/// Entrypoint for headless runtime with given initial configuration
+///
+/// See [`run_headless`]
+pub fn run_headless_with(config: RuntimeConfig, main: impl Future<Output = ()>) {
+    executor::headless::run(Backend::Test, config, main)
+}
+
+/// Create fake [`WindowId`] for synthetic events
+pub fn window_id(id: u64) -> WindowId {
+    WindowId::from(id)
+}
+
+/// Create fake [`DeviceId`] for synthetic events
+pub fn device_id() -> DeviceId {
+    DeviceId::dummy()
+}
@@ -0,0 +1,115 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use winit::{dpi::PhysicalSize, event::WindowEvent, window::WindowId};
+
+use crate::executor::inject_window_event;
+
+/// Start of [`NullWindow`] ids, to avoid collision with ids made using [`super::window_id`]
+const NULL_WINDOW_ID_START: u64 = 1 << 48;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(NULL_WINDOW_ID_START);
+
+/// Window-less render target backed by CPU buffer, for headless runtime
+///
+/// Pixels are stored in `0RGB` format, same as softbuffer
+#[derive(Debug)]
+pub struct NullWindow {
+    id: WindowId,
+    size: PhysicalSize<u32>,
+    buffer: Vec<u32>,
+}
+
+impl NullWindow {
+    /// Create new null window with given size
+    pub fn new(size: PhysicalSize<u32>) -> Self {
+        Self {
+            id: WindowId::from(NEXT_ID.fetch_add(1, Ordering::Relaxed)),
+            size,
+            buffer: vec![0; buffer_len(size)],
+        }
+    }
+
+    /// Id of this window, used for events dispatched to this window
+    pub const fn id(&self) -> WindowId {
+        self.id
+    }
+
+    pub const fn inner_size(&self) -> PhysicalSize<u32> {
+        self.size
+    }
+
+    /// Resize window buffer and dispatch [`WindowEvent::Resized`]
+    pub fn set_inner_size(&mut self, size: PhysicalSize<u32>) {
+        self.size = size;
+        self.buffer.resize(buffer_len(size), 0);
+
+        inject_window_event(self.id, WindowEvent::Resized(size));
+    }
+
+    /// Dispatch [`WindowEvent::RedrawRequested`]
+    pub fn request_redraw(&self) {
+        inject_window_event(self.id, WindowEvent::RedrawRequested);
+    }
+
+    /// Draw a frame using closure with window size and pixel buffer
+    pub fn present(&mut self, draw: impl FnOnce(PhysicalSize<u32>, &mut [u32])) {
+        draw(self.size, &mut self.buffer);
+    }
+
+    /// Capture current window buffer
+    pub fn screenshot(&self) -> Screenshot {
+        Screenshot {
+            size: self.size,
+            pixels: self.buffer.clone(),
+        }
+    }
+}
+
+/// Captured [`NullWindow`] buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Screenshot {
+    pub size: PhysicalSize<u32>,
+
+    /// Pixels in `0RGB` format
+    pub pixels: Vec<u32>,
+}
+
+impl Screenshot {
+    /// Write as binary PPM image
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(
+            writer,
+            "P6\n{} {}\n255\n",
+            self.size.width, self.size.height
+        )?;
+
+        for pixel in &self.pixels {
+            writer.write_all(&pixel.to_be_bytes()[1..])?;
+        }
+
+        Ok(())
+    }
+
+    /// Save as binary PPM image at given path
+    pub fn save_ppm(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_ppm(&mut writer)?;
+
+        writer.flush()
+    }
+}
+
+fn buffer_len(size: PhysicalSize<u32>) -> usize {
+    size.width as usize * size.height as usize
+}
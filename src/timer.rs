@@ -5,78 +5,162 @@
  */
 
 use std::{
-    num::NonZeroU64,
-    sync::atomic::{AtomicU64, Ordering},
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    task::{ready, Context, Poll},
 };
 
-use futures_intrusive::timer::{Clock, Timer, TimerService};
+use futures_intrusive::timer::{Timer, TimerService};
+use instant::{Duration, Instant};
+use pin_project_lite::pin_project;
 
-pub use futures_intrusive::timer::TimerFuture;
-use instant::Duration;
+use crate::executor::{executor_handle, RuntimeError};
 
-use crate::executor::executor_handle;
+/// Time source of runtime timers
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+
+    /// Manual clock only advances explicitly, so event loop never sleeps until its deadlines.
+    /// Advancing manual clock must wake event loop.
+    fn is_manual(&self) -> bool {
+        false
+    }
+}
+
+/// [`Clock`] using system monotonic time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Clock counting microseconds since timer creation
+struct MicrosClock {
+    clock: &'static dyn Clock,
+    start: Instant,
+}
+
+impl MicrosClock {
+    fn to_micros(&self, instant: Instant) -> u64 {
+        instant.saturating_duration_since(self.start).as_micros() as u64
+    }
+
+    fn instant_at(&self, micros: u64) -> Instant {
+        self.start + Duration::from_micros(micros)
+    }
+}
+
+impl futures_intrusive::timer::Clock for MicrosClock {
+    fn now(&self) -> u64 {
+        self.to_micros(self.clock.now())
+    }
+}
+
+impl Debug for MicrosClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MicrosClock")
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
 
 #[derive(Debug)]
 pub(crate) struct ExecutorTimer {
+    clock: &'static MicrosClock,
     service: TimerService,
     next_expiration: AtomicU64,
+    active: AtomicUsize,
 }
 
 impl ExecutorTimer {
-    pub fn new() -> Self {
-        struct InstantClock;
+    /// Sentinel of [`ExecutorTimer::next_expiration`] when there is no timer
+    const NONE: u64 = u64::MAX;
 
-        impl Clock for InstantClock {
-            fn now(&self) -> u64 {
-                instant::now() as u64
-            }
-        }
+    pub fn new(clock: &'static dyn Clock) -> Self {
+        // Leaked once per runtime, as timer service requires static clock
+        let clock: &'static MicrosClock = Box::leak(Box::new(MicrosClock {
+            clock,
+            start: clock.now(),
+        }));
 
         Self {
-            service: TimerService::new(&InstantClock),
-            next_expiration: AtomicU64::new(0),
+            clock,
+            service: TimerService::new(clock),
+            next_expiration: AtomicU64::new(Self::NONE),
+            active: AtomicUsize::new(0),
         }
     }
 
     pub fn update_next(&self) -> UpdateState {
         let next = self.next_expiration.load(Ordering::Acquire);
-        if next == 0 {
+        if next == Self::NONE {
             return UpdateState::None;
         }
 
-        let now = instant::now() as u64;
-
-        if next <= now {
+        if next <= self.now_micros() {
             self.service.check_expirations();
             self.next_expiration.store(
-                self.service.next_expiration().unwrap_or(0),
+                self.service.next_expiration().unwrap_or(Self::NONE),
                 Ordering::Release,
             );
 
             UpdateState::Triggered
+        } else if self.clock.clock.is_manual() {
+            UpdateState::None
         } else {
-            UpdateState::WaitTimeout(NonZeroU64::new(next - now).unwrap())
+            UpdateState::WaitUntil(self.clock.instant_at(next))
         }
     }
 
-    pub fn delay(&self, delay: Duration) -> TimerFuture {
-        self.deadline(instant::now() as u64 + delay.as_millis() as u64)
+    fn now_micros(&self) -> u64 {
+        futures_intrusive::timer::Clock::now(self.clock)
     }
 
-    pub fn deadline(&self, timestamp: u64) -> TimerFuture {
+    /// Current time of the clock
+    pub fn now(&self) -> Instant {
+        self.clock.clock.now()
+    }
+
+    pub fn stats(&self) -> TimerStats {
+        let next = self.next_expiration.load(Ordering::Acquire);
+
+        TimerStats {
+            active: self.active.load(Ordering::Relaxed),
+            next_deadline: (next != Self::NONE).then(|| self.clock.instant_at(next)),
+        }
+    }
+
+    pub fn delay(&self, delay: Duration, slack: Duration) -> TimerFuture<'_> {
+        self.deadline(self.now() + delay, slack)
+    }
+
+    /// Register timer, with deadline rounded up to multiple of `slack` so nearby timers expire together
+    pub fn deadline(&self, deadline: Instant, slack: Duration) -> TimerFuture<'_> {
+        let mut timestamp = self.clock.to_micros(deadline);
+
+        let slack = slack.as_micros() as u64;
+        if slack > 1 {
+            timestamp = timestamp.saturating_add(slack - 1) / slack * slack;
+        }
         let future = self.service.deadline(timestamp);
+        self.schedule(timestamp);
 
-        let _ = self
-            .next_expiration
-            .fetch_update(Ordering::Release, Ordering::Acquire, |next| {
-                if next == 0 || next > timestamp {
-                    Some(timestamp)
-                } else {
-                    None
-                }
-            });
+        self.active.fetch_add(1, Ordering::Relaxed);
+        TimerFuture {
+            inner: future,
+            timer: Some(self),
+            timestamp,
+        }
+    }
 
-        future
+    /// Make sure event loop wakes before given timestamp
+    fn schedule(&self, timestamp: u64) {
+        self.next_expiration.fetch_min(timestamp, Ordering::AcqRel);
     }
 }
 
@@ -84,7 +168,137 @@ impl ExecutorTimer {
 pub(crate) enum UpdateState {
     None,
     Triggered,
-    WaitTimeout(NonZeroU64),
+    WaitUntil(Instant),
+}
+
+/// Timer statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerStats {
+    /// Number of pending timers
+    pub active: usize,
+
+    /// Earliest deadline of pending timers
+    pub next_deadline: Option<Instant>,
+}
+
+pin_project! {
+    /// Future completes after timer deadline
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TimerFuture<'a> {
+        #[pin]
+        inner: futures_intrusive::timer::TimerFuture<'a>,
+        timer: Option<&'a ExecutorTimer>,
+        timestamp: u64,
+    }
+
+    impl PinnedDrop for TimerFuture<'_> {
+        fn drop(this: Pin<&mut Self>) {
+            if let Some(timer) = this.project().timer.take() {
+                timer.active.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl Future for TimerFuture<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.project();
+
+        let poll = this.inner.poll(cx);
+        if let Some(timer) = this.timer {
+            if poll.is_ready() {
+                timer.active.fetch_sub(1, Ordering::Relaxed);
+                *this.timer = None;
+            } else {
+                // Timer is registered on first poll, possibly after expirations are checked
+                timer.schedule(*this.timestamp);
+            }
+        }
+
+        poll
+    }
+}
+
+pin_project! {
+    /// Resettable timer, for debouncing and throttling
+    ///
+    /// Completes once after its deadline and stays pending until reset again.
+    /// Inactive timer never completes.
+    ///
+    /// ```ignore
+    /// let mut timer = pin!(TimerHandle::new());
+    ///
+    /// loop {
+    ///     match future::or(async { Some(resized.next().await) }, async { timer.as_mut().await; None }).await {
+    ///         // Restart timer on every resize
+    ///         Some(size) => timer.as_mut().reset(Duration::from_millis(200)),
+    ///         // No resize for 200ms
+    ///         None => relayout(),
+    ///     }
+    /// }
+    /// ```
+    #[derive(Debug, Default)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct TimerHandle {
+        #[pin]
+        timer: Option<TimerFuture<'static>>,
+    }
+}
+
+impl TimerHandle {
+    /// Create inactive timer
+    pub const fn new() -> Self {
+        Self { timer: None }
+    }
+
+    /// Create timer expiring after given duration
+    pub fn after(delay: Duration) -> Self {
+        Self {
+            timer: Some(wait(delay)),
+        }
+    }
+
+    /// Check if timer is scheduled and did not complete yet
+    pub fn is_active(&self) -> bool {
+        self.timer.is_some()
+    }
+
+    /// Reschedule timer to expire after given duration, from now
+    pub fn reset(self: Pin<&mut Self>, delay: Duration) {
+        self.project().timer.set(Some(wait(delay)));
+    }
+
+    /// Reschedule timer to expire at given instant
+    pub fn reset_until(self: Pin<&mut Self>, deadline: Instant) {
+        self.project().timer.set(Some(wait_until(deadline)));
+    }
+
+    /// Cancel scheduled timer
+    pub fn cancel(self: Pin<&mut Self>) {
+        self.project().timer.set(None);
+    }
+}
+
+impl Future for TimerHandle {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut timer = self.project().timer;
+
+        match timer.as_mut().as_pin_mut() {
+            Some(fut) => {
+                ready!(fut.poll(cx));
+                timer.set(None);
+
+                Poll::Ready(())
+            }
+
+            None => Poll::Pending,
+        }
+    }
 }
 
 /// Create Future waiting for given duration
@@ -92,7 +306,36 @@ pub fn wait(delay: Duration) -> TimerFuture<'static> {
     executor_handle().wait(delay)
 }
 
-/// Create Future waiting for given timestamp
+/// Create Future waiting until given instant
+pub fn wait_until(deadline: Instant) -> TimerFuture<'static> {
+    executor_handle().wait_until(deadline)
+}
+
+/// Create Future waiting for given timestamp, in milliseconds since UNIX epoch
 pub fn wait_deadline(timestamp: u64) -> TimerFuture<'static> {
     executor_handle().wait_deadline(timestamp)
 }
+
+/// Create Future waiting for given duration, failing once runtime exits
+///
+/// See [`ExecutorHandle::try_wait`](crate::executor::handle::ExecutorHandle::try_wait)
+pub async fn try_wait(delay: Duration) -> Result<(), RuntimeError> {
+    executor_handle().try_wait(delay).await
+}
+
+/// Create Future waiting until given instant, failing once runtime exits
+pub async fn try_wait_until(deadline: Instant) -> Result<(), RuntimeError> {
+    executor_handle().try_wait_until(deadline).await
+}
+
+/// Current time of runtime clock
+///
+/// See [`RuntimeConfig::with_clock`](crate::config::RuntimeConfig::with_clock)
+pub fn now() -> Instant {
+    executor_handle().now()
+}
+
+/// Current timer statistics
+pub fn stats() -> TimerStats {
+    executor_handle().timer_stats()
+}
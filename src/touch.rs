@@ -0,0 +1,225 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Touch gesture recognition
+//!
+//! Runtime recognizes gestures from [`WindowEvent::Touch`] events of every window and emits them on [`gesture`] event source,
+//! after the touch event is dispatched.
+
+use std::{cell::RefCell, collections::HashMap};
+
+use instant::{Duration, Instant};
+use winit::{
+    dpi::PhysicalPosition,
+    event::{Touch, TouchPhase, WindowEvent},
+    window::WindowId,
+};
+
+use crate::{emit, spawn_local_ui_task, task::Task, timer};
+
+/// Maximum distance a touch can move and still be a tap, in physical pixels
+pub const TAP_SLOP: f64 = 16.0;
+
+/// Maximum duration of a tap
+pub const TAP_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Maximum interval between taps of a double tap
+pub const DOUBLE_TAP_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Duration a touch should be held still to be a long press
+pub const LONG_PRESS_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Recognized touch gesture
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Gesture {
+    /// Single touch pressed and released quickly without moving
+    Tap { position: PhysicalPosition<f64> },
+
+    /// Second tap close to previous one. It is not reported as [`Gesture::Tap`].
+    DoubleTap { position: PhysicalPosition<f64> },
+
+    /// Single touch held still. Touch is not reported as [`Gesture::Tap`] once released.
+    LongPress { position: PhysicalPosition<f64> },
+
+    /// Two touches moved, with distance ratio to previous pinch event
+    Pinch {
+        center: PhysicalPosition<f64>,
+        scale: f64,
+    },
+}
+
+define_event!(
+    /// Touch gesture is recognized on the window
+    pub gesture: (WindowId, Gesture)
+);
+
+thread_local! {
+    static RECOGNIZERS: RefCell<HashMap<WindowId, Recognizer>> = RefCell::new(HashMap::new());
+}
+
+pub(crate) fn reset() {
+    let recognizers = RECOGNIZERS.with(|recognizers| recognizers.take());
+    drop(recognizers);
+}
+
+/// Feed window event to gesture recognizer of the window
+pub(crate) fn process_event(window_id: WindowId, event: &WindowEvent) {
+    let recognized = RECOGNIZERS.with(|recognizers| {
+        let mut recognizers = recognizers.borrow_mut();
+
+        match event {
+            WindowEvent::Touch(touch) => recognizers
+                .entry(window_id)
+                .or_default()
+                .update(window_id, touch),
+
+            WindowEvent::Destroyed => {
+                recognizers.remove(&window_id);
+                None
+            }
+
+            _ => None,
+        }
+    });
+
+    if let Some(recognized) = recognized {
+        emit!(gesture(), (window_id, recognized));
+    }
+}
+
+#[derive(Debug, Default)]
+struct Recognizer {
+    touches: HashMap<u64, PhysicalPosition<f64>>,
+    press: Option<Press>,
+    last_tap: Option<(Instant, PhysicalPosition<f64>)>,
+    pinch_distance: Option<f64>,
+}
+
+impl Recognizer {
+    fn update(&mut self, window_id: WindowId, touch: &Touch) -> Option<Gesture> {
+        match touch.phase {
+            TouchPhase::Started => {
+                self.touches.insert(touch.id, touch.location);
+
+                match self.touches.len() {
+                    1 => self.press = Some(Press::new(window_id, touch)),
+
+                    2 => {
+                        self.press = None;
+                        self.pinch_distance = self.pinch().map(|(_, distance)| distance);
+                    }
+
+                    _ => self.pinch_distance = None,
+                }
+
+                None
+            }
+
+            TouchPhase::Moved => {
+                self.touches.insert(touch.id, touch.location);
+
+                if let Some(press) = &self.press {
+                    if press.id == touch.id && distance(press.origin, touch.location) > TAP_SLOP {
+                        self.press = None;
+                    }
+                }
+
+                let (center, distance) = self.pinch()?;
+                let previous = self.pinch_distance.replace(distance)?;
+
+                (previous > 0.0).then(|| Gesture::Pinch {
+                    center,
+                    scale: distance / previous,
+                })
+            }
+
+            TouchPhase::Ended => {
+                self.touches.remove(&touch.id);
+                self.pinch_distance = None;
+
+                let press = self.press.take().filter(|press| press.id == touch.id)?;
+                let now = timer::now();
+                if press.is_long_pressed() || now - press.started > TAP_TIMEOUT {
+                    return None;
+                }
+
+                let position = touch.location;
+                match self.last_tap.take() {
+                    Some((last, last_position))
+                        if now - last <= DOUBLE_TAP_INTERVAL
+                            && distance(last_position, position) <= TAP_SLOP =>
+                    {
+                        Some(Gesture::DoubleTap { position })
+                    }
+
+                    _ => {
+                        self.last_tap = Some((now, position));
+                        Some(Gesture::Tap { position })
+                    }
+                }
+            }
+
+            TouchPhase::Cancelled => {
+                self.touches.remove(&touch.id);
+                self.press = None;
+                self.pinch_distance = None;
+
+                None
+            }
+        }
+    }
+
+    /// Center and distance of two touches
+    fn pinch(&self) -> Option<(PhysicalPosition<f64>, f64)> {
+        if self.touches.len() != 2 {
+            return None;
+        }
+
+        let mut touches = self.touches.values();
+        let (a, b) = (*touches.next()?, *touches.next()?);
+
+        Some((
+            PhysicalPosition::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+            distance(a, b),
+        ))
+    }
+}
+
+/// Single touch which can be a tap or long press
+#[derive(Debug)]
+struct Press {
+    id: u64,
+    origin: PhysicalPosition<f64>,
+    started: Instant,
+
+    /// Long press timer, cancelled once dropped
+    long_press: Task<()>,
+}
+
+impl Press {
+    fn new(window_id: WindowId, touch: &Touch) -> Self {
+        let position = touch.location;
+
+        Self {
+            id: touch.id,
+            origin: position,
+            started: timer::now(),
+            long_press: spawn_local_ui_task(async move {
+                timer::wait(LONG_PRESS_TIMEOUT).await;
+                emit!(gesture(), (window_id, Gesture::LongPress { position }));
+            }),
+        }
+    }
+
+    fn is_long_pressed(&self) -> bool {
+        self.long_press.is_finished()
+    }
+}
+
+fn distance(a: PhysicalPosition<f64>, b: PhysicalPosition<f64>) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
@@ -0,0 +1,166 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Window icon and custom cursor from encoded images
+//!
+//! Images are decoded off the runtime thread, so decoding does not block event loop.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+use image::ImageError;
+use winit::window::{BadIcon, BadImage, CustomCursor, CustomCursorSource, Icon, Window};
+
+use crate::{executor::with_eventloop_target, spawn_ui_task};
+
+/// Error of decoding window icon or cursor
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum IconError {
+    /// Image could not be decoded
+    Decode(ImageError),
+
+    /// Decoded image is not usable as icon
+    Icon(BadIcon),
+
+    /// Decoded image is not usable as cursor
+    Cursor(BadImage),
+
+    /// Image is larger than maximum cursor size
+    TooLarge { width: u32, height: u32 },
+}
+
+impl Display for IconError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IconError::Decode(err) => write!(f, "failed to decode icon: {err}"),
+            IconError::Icon(err) => write!(f, "invalid icon: {err}"),
+            IconError::Cursor(err) => write!(f, "invalid cursor: {err}"),
+            IconError::TooLarge { width, height } => {
+                write!(f, "cursor image is too large: {width}x{height}")
+            }
+        }
+    }
+}
+
+impl Error for IconError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IconError::Decode(err) => Some(err),
+            IconError::Icon(err) => Some(err),
+            IconError::Cursor(err) => Some(err),
+            IconError::TooLarge { .. } => None,
+        }
+    }
+}
+
+fn decode(bytes: &[u8]) -> Result<Icon, IconError> {
+    let image = image::load_from_memory(bytes)
+        .map_err(IconError::Decode)?
+        .into_rgba8();
+    let (width, height) = image.dimensions();
+
+    Icon::from_rgba(image.into_raw(), width, height).map_err(IconError::Icon)
+}
+
+fn decode_cursor_source(
+    bytes: &[u8],
+    hotspot: (u16, u16),
+) -> Result<CustomCursorSource, IconError> {
+    let image = image::load_from_memory(bytes)
+        .map_err(IconError::Decode)?
+        .into_rgba8();
+    let (width, height) = image.dimensions();
+
+    let (Ok(cursor_width), Ok(cursor_height)) = (u16::try_from(width), u16::try_from(height))
+    else {
+        return Err(IconError::TooLarge { width, height });
+    };
+
+    CustomCursor::from_rgba(
+        image.into_raw(),
+        cursor_width,
+        cursor_height,
+        hotspot.0,
+        hotspot.1,
+    )
+    .map_err(IconError::Cursor)
+}
+
+/// Decode encoded image into [`Icon`], on a blocking thread
+///
+/// Decodes on current thread on platforms without thread support like wasm.
+pub async fn decode_icon(bytes: impl Into<Vec<u8>>) -> Result<Icon, IconError> {
+    let bytes = bytes.into();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    return crate::spawn_blocking(move || decode(&bytes)).await;
+
+    #[cfg(target_arch = "wasm32")]
+    decode(&bytes)
+}
+
+/// Decode encoded image and set it as icon of the window
+///
+/// Icon is set on runtime thread, so it can be called from any thread.
+/// ```ignore
+/// window::set_icon_from_bytes(&window, include_bytes!("icon.png")).await?;
+/// ```
+pub async fn set_icon_from_bytes(
+    window: &Arc<Window>,
+    bytes: impl Into<Vec<u8>>,
+) -> Result<(), IconError> {
+    let icon = decode_icon(bytes).await?;
+
+    let window = window.clone();
+    spawn_ui_task(async move { window.set_window_icon(Some(icon)) }).await;
+
+    Ok(())
+}
+
+/// Decode encoded image into [`CustomCursorSource`] with `hotspot` in pixels, on a blocking thread
+///
+/// See [`decode_icon`]
+pub async fn decode_cursor(
+    bytes: impl Into<Vec<u8>>,
+    hotspot: (u16, u16),
+) -> Result<CustomCursorSource, IconError> {
+    let bytes = bytes.into();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    return crate::spawn_blocking(move || decode_cursor_source(&bytes, hotspot)).await;
+
+    #[cfg(target_arch = "wasm32")]
+    decode_cursor_source(&bytes, hotspot)
+}
+
+/// Decode encoded image and set it as cursor of the window, returning created cursor for reuse
+///
+/// Cursor is created and set on runtime thread, so it can be called from any thread.
+/// ```ignore
+/// let cursor = window::set_cursor_from_bytes(&window, include_bytes!("pointer.png"), (0, 0)).await?;
+/// ```
+pub async fn set_cursor_from_bytes(
+    window: &Arc<Window>,
+    bytes: impl Into<Vec<u8>>,
+    hotspot: (u16, u16),
+) -> Result<CustomCursor, IconError> {
+    let source = decode_cursor(bytes, hotspot).await?;
+
+    let window = window.clone();
+    let cursor = spawn_ui_task(async move {
+        let cursor = with_eventloop_target(|target| target.create_custom_cursor(source));
+        window.set_cursor(cursor.clone());
+
+        cursor
+    })
+    .await;
+
+    Ok(cursor)
+}
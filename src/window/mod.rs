@@ -0,0 +1,372 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Window workflow helpers
+
+#[cfg(feature = "image")]
+mod icon;
+pub mod state;
+
+#[cfg(feature = "image")]
+pub use icon::{decode_cursor, decode_icon, set_cursor_from_bytes, set_icon_from_bytes, IconError};
+
+use std::{
+    collections::HashMap,
+    pin::pin,
+    sync::{Arc, Weak},
+};
+
+use futures_lite::{future, Future};
+use instant::Duration;
+use parking_lot::{const_mutex, Mutex};
+use winit::{
+    dpi::{LogicalSize, PhysicalSize},
+    error::OsError,
+    event::WindowEvent,
+    window::{Fullscreen, Window, WindowAttributes, WindowId},
+};
+
+use crate::{
+    build_window, emit,
+    executor::{executor_handle, inject_window_event},
+    lifecycle, timer,
+};
+
+/// Maximum wait for window events confirming fullscreen or minimized state change
+///
+/// Some platforms do not send any event when nothing visibly changes, like entering fullscreen on a window already covering monitor.
+const TRANSITION_TIMEOUT: Duration = Duration::from_secs(1);
+
+define_event!(
+    /// Close request of guarded window is intercepted
+    pub(crate) close_intercepted: WindowId
+);
+
+define_event!(
+    /// Focus of the window is changed
+    pub focused: (WindowId, bool)
+);
+
+define_event!(
+    /// Window is hidden from view or becomes visible again
+    pub occluded: (WindowId, bool)
+);
+
+define_event!(
+    /// Window event of [`register`]ed window with the window itself, emitted after [`window`](crate::window) event source
+    pub registered: (&Window, &mut WindowEvent)
+);
+
+define_event!(
+    /// Window is being closed with [`close`], every handles of the window should be dropped
+    pub closed: WindowId
+);
+
+static REGISTRY: Mutex<Option<HashMap<WindowId, Entry>>> = const_mutex(None);
+
+struct Entry {
+    window: Weak<Window>,
+
+    /// Handle owned by registry, dropped on [`close`]
+    _owned: Option<Arc<Window>>,
+}
+
+fn insert(window: &Arc<Window>, owned: bool) {
+    let entry = Entry {
+        window: Arc::downgrade(window),
+        _owned: owned.then(|| window.clone()),
+    };
+
+    let previous = REGISTRY
+        .lock()
+        .get_or_insert_with(HashMap::new)
+        .insert(window.id(), entry);
+
+    // Dropped outside of the lock
+    drop(previous);
+}
+
+fn remove(id: WindowId) -> Option<Entry> {
+    REGISTRY.lock().as_mut()?.remove(&id)
+}
+
+/// Register window, so its events are emitted on [`registered`] event source and it can be looked up by [`get`]
+///
+/// Registry does not keep window alive. Window is unregistered once destroyed.
+pub fn register(window: &Arc<Window>) {
+    insert(window, false);
+}
+
+/// Register window owned by registry, so it stays alive until [`close`]d
+///
+/// See [`register`]
+pub fn register_owned(window: Window) -> Arc<Window> {
+    let window = Arc::new(window);
+    insert(&window, true);
+
+    window
+}
+
+pub fn unregister(id: WindowId) {
+    drop(remove(id));
+}
+
+/// Get registered window, if it is alive
+pub fn get(id: WindowId) -> Option<Arc<Window>> {
+    let mut registry = REGISTRY.lock();
+    let registry = registry.as_mut()?;
+
+    match registry.get(&id)?.window.upgrade() {
+        Some(window) => Some(window),
+
+        None => {
+            registry.remove(&id);
+            None
+        }
+    }
+}
+
+/// Close the window and wait until it is destroyed, returns `false` if window is not created by runtime or destroyed already
+///
+/// Handle owned by registry is dropped, and [`closed`] is emitted so other owners can drop their handles.
+/// Window is destroyed once every handles are dropped.
+/// ```ignore
+/// let window = window::register_owned(build_window(attributes)?);
+/// // ...
+/// window::close(window.id()).await;
+/// ```
+pub async fn close(id: WindowId) -> bool {
+    if !executor_handle().has_window(id) {
+        return false;
+    }
+
+    let mut destroyed = pin!(crate::window().wait_for(
+        |(window_id, event)| {
+            (window_id == id && matches!(event, WindowEvent::Destroyed)).then_some(())
+        },
+        None,
+    ));
+
+    // Start listening before window can be destroyed
+    let _ = future::poll_once(destroyed.as_mut()).await;
+
+    unregister(id);
+    emit!(closed(), id);
+
+    let _ = destroyed.await;
+    true
+}
+
+/// Drop every registered windows once runtime exits
+pub(crate) fn reset() {
+    let registry = REGISTRY.lock().take();
+    drop(registry);
+}
+
+/// Emit window event with registered window
+pub(crate) fn dispatch_registered(id: WindowId, event: &mut WindowEvent) {
+    let Some(window) = get(id) else {
+        return;
+    };
+
+    emit!(registered(), (&window, event));
+
+    if let WindowEvent::Destroyed = event {
+        unregister(id);
+    }
+}
+
+/// Inner size and scale factor of a window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowMetrics {
+    pub inner_size: PhysicalSize<u32>,
+    pub scale_factor: f64,
+}
+
+impl WindowMetrics {
+    /// Read current metrics of the window
+    pub fn of(window: &Window) -> Self {
+        Self {
+            inner_size: window.inner_size(),
+            scale_factor: window.scale_factor(),
+        }
+    }
+
+    /// Inner size in logical pixels
+    pub fn logical_size(&self) -> LogicalSize<f64> {
+        self.inner_size.to_logical(self.scale_factor)
+    }
+}
+
+/// Latest metrics of the window, without needing its [`Window`]
+///
+/// Metrics are updated before [`WindowEvent::Resized`] and [`WindowEvent::ScaleFactorChanged`] are dispatched.
+/// See [`ExecutorHandle::metrics`](crate::executor::handle::ExecutorHandle::metrics)
+pub fn metrics(id: WindowId) -> Option<WindowMetrics> {
+    executor_handle().metrics(id)
+}
+
+/// Check if the window has input focus
+///
+/// See [`ExecutorHandle::is_focused`](crate::executor::handle::ExecutorHandle::is_focused)
+pub fn is_focused(id: WindowId) -> bool {
+    executor_handle().is_focused(id)
+}
+
+/// Check if the window is hidden from view
+///
+/// See [`ExecutorHandle::is_occluded`](crate::executor::handle::ExecutorHandle::is_occluded)
+pub fn is_occluded(id: WindowId) -> bool {
+    executor_handle().is_occluded(id)
+}
+
+/// Create new window once application is resumed, or immediately if it is resumed already
+///
+/// Windows should not be created before first [`resumed`](crate::resumed) event, as it fails on mobile platforms.
+/// See [`build_window`]
+pub async fn create_when_resumed(attributes: WindowAttributes) -> Result<Window, OsError> {
+    lifecycle::wait_resumed().await;
+    build_window(attributes)
+}
+
+/// Intercepted close request, passed to confirmation of [`confirm_close`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CloseRequest {
+    window_id: WindowId,
+}
+
+impl CloseRequest {
+    pub const fn window_id(&self) -> WindowId {
+        self.window_id
+    }
+}
+
+/// Confirm every close request of the window before it is dispatched
+///
+/// [`WindowEvent::CloseRequested`] of the window is withheld from other listeners while `confirm` runs.
+/// If it resolves to `true`, close request is dispatched again and window can be closed as usual. Otherwise it is cancelled.
+/// Close requests arrived while confirming are ignored.
+///
+/// ```ignore
+/// confirm_close(&window, |_| async {
+///     !has_unsaved_changes() || ask_discard_changes().await
+/// })
+/// .await
+/// ```
+pub async fn confirm_close<F, Fut>(window: &Window, mut confirm: F) -> !
+where
+    F: FnMut(CloseRequest) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let id = window.id();
+    let _guard = CloseGuard::new(id);
+
+    loop {
+        let Some(request) = close_intercepted()
+            .once(move |window_id, _| (window_id == id).then_some(CloseRequest { window_id }))
+            .await
+        else {
+            continue;
+        };
+
+        if confirm(request).await {
+            executor_handle().allow_close(id);
+            inject_window_event(id, WindowEvent::CloseRequested);
+        }
+    }
+}
+
+/// Change fullscreen mode and wait until window is resized, returns new size
+///
+/// Returns immediately with current size if window is already in given mode,
+/// or with size at that time if resize is not confirmed in one second.
+pub async fn set_fullscreen_async(
+    window: &Window,
+    fullscreen: Option<Fullscreen>,
+) -> PhysicalSize<u32> {
+    if window.fullscreen() == fullscreen {
+        return window.inner_size();
+    }
+
+    transition(
+        window,
+        |event| match event {
+            WindowEvent::Resized(size) => Some(*size),
+            _ => None,
+        },
+        move || window.set_fullscreen(fullscreen),
+    )
+    .await
+    .unwrap_or_else(|| window.inner_size())
+}
+
+/// Minimize or restore window and wait until it is confirmed by window events
+///
+/// Returns immediately if minimized state is known to be same already, and gives up waiting after one second.
+pub async fn set_minimized_async(window: &Window, minimized: bool) {
+    if window.is_minimized() == Some(minimized) {
+        return;
+    }
+
+    transition(
+        window,
+        |event| match *event {
+            WindowEvent::Resized(size) => {
+                ((size.width == 0 || size.height == 0) == minimized).then_some(())
+            }
+            WindowEvent::Occluded(occluded) => (occluded == minimized).then_some(()),
+            _ => None,
+        },
+        move || window.set_minimized(minimized),
+    )
+    .await;
+}
+
+/// Apply window change and wait until `confirm` maps window event into [`Option::Some`], returns [`None`] on timeout
+async fn transition<R: Send>(
+    window: &Window,
+    mut confirm: impl FnMut(&WindowEvent) -> Option<R> + Send,
+    apply: impl FnOnce(),
+) -> Option<R> {
+    let id = window.id();
+
+    let mut confirmed = crate::window().wait_map(move |(window_id, event)| {
+        if window_id == id {
+            confirm(event)
+        } else {
+            None
+        }
+    });
+
+    // Register listener first, events can be emitted while applying on some platforms
+    if let Some(output) = future::poll_once(&mut confirmed).await {
+        return Some(output);
+    }
+    apply();
+
+    future::or(async { Some(confirmed.await) }, async {
+        timer::wait(TRANSITION_TIMEOUT).await;
+        None
+    })
+    .await
+}
+
+/// Intercepts close requests of the window while alive
+#[derive(Debug)]
+struct CloseGuard(WindowId);
+
+impl CloseGuard {
+    fn new(id: WindowId) -> Self {
+        executor_handle().guard_close(id);
+        Self(id)
+    }
+}
+
+impl Drop for CloseGuard {
+    fn drop(&mut self) {
+        executor_handle().unguard_close(self.0);
+    }
+}
@@ -0,0 +1,255 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+//! Window state persistence, for reopening windows where they were left
+//!
+//! Set a [`StateStore`] with [`set_store`] and create windows using [`build_window_restored`].
+//! Geometry is recorded from window events and saved to the store once window loses focus, is requested to close or destroyed.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+    sync::Arc,
+};
+
+use parking_lot::{const_mutex, Mutex};
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    error::OsError,
+    event::WindowEvent,
+    window::{Window, WindowAttributes},
+};
+
+use crate::{build_window, executor::with_eventloop_target, spawn_ui_task};
+
+/// Saved state of a window
+///
+/// Position and size are of the window when it is not maximized, minimized or fullscreen.
+/// Can be converted to and from string using [`Display`] and [`FromStr`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowState {
+    /// Outer position, if supported by platform
+    pub position: Option<PhysicalPosition<i32>>,
+    pub size: PhysicalSize<u32>,
+    pub maximized: bool,
+
+    /// Name of monitor the window was on
+    pub monitor: Option<String>,
+}
+
+impl WindowState {
+    /// Capture current state of the window
+    pub fn capture(window: &Window) -> Self {
+        let mut state = Self {
+            position: None,
+            size: window.inner_size(),
+            maximized: false,
+            monitor: None,
+        };
+        state.record(window);
+
+        state
+    }
+
+    fn record(&mut self, window: &Window) {
+        self.maximized = window.is_maximized();
+        self.monitor = window.current_monitor().and_then(|monitor| monitor.name());
+
+        let normal =
+            !self.maximized && window.is_minimized() != Some(true) && window.fullscreen().is_none();
+        if !normal {
+            return;
+        }
+
+        let size = window.inner_size();
+        if size.width > 0 && size.height > 0 {
+            self.size = size;
+        }
+
+        if let Ok(position) = window.outer_position() {
+            self.position = Some(position);
+        }
+    }
+
+    /// Apply saved state to the attributes
+    ///
+    /// Position is applied only if saved monitor is connected, so window is not placed out of screen.
+    pub fn apply(&self, attributes: WindowAttributes) -> WindowAttributes {
+        let attributes = attributes
+            .with_inner_size(self.size)
+            .with_maximized(self.maximized);
+
+        match self.position {
+            Some(position) if self.on_screen(position) => attributes.with_position(position),
+            _ => attributes,
+        }
+    }
+
+    fn on_screen(&self, position: PhysicalPosition<i32>) -> bool {
+        with_eventloop_target(|target| {
+            target
+                .available_monitors()
+                .any(|monitor| match self.monitor {
+                    Some(ref name) => monitor.name().as_ref() == Some(name),
+
+                    // Fallback to monitor bounds if name is not available
+                    None => {
+                        let origin = monitor.position();
+                        let size = monitor.size();
+
+                        (origin.x..origin.x + size.width as i32).contains(&position.x)
+                            && (origin.y..origin.y + size.height as i32).contains(&position.y)
+                    }
+                })
+        })
+    }
+}
+
+impl Display for WindowState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(position) => write!(f, "{},{}", position.x, position.y)?,
+            None => f.write_str("-,-")?,
+        }
+
+        write!(
+            f,
+            ",{},{},{}",
+            self.size.width, self.size.height, self.maximized as u8
+        )?;
+
+        // Monitor name goes last, as it can contain commas
+        if let Some(ref monitor) = self.monitor {
+            write!(f, ",{monitor}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for WindowState {
+    type Err = ParseStateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.splitn(6, ',');
+        let mut next = || fields.next().ok_or(ParseStateError);
+
+        let position = match (next()?, next()?) {
+            ("-", "-") => None,
+            (x, y) => Some(PhysicalPosition::new(
+                x.parse().map_err(|_| ParseStateError)?,
+                y.parse().map_err(|_| ParseStateError)?,
+            )),
+        };
+
+        let size = PhysicalSize::new(
+            next()?.parse().map_err(|_| ParseStateError)?,
+            next()?.parse().map_err(|_| ParseStateError)?,
+        );
+
+        let maximized = match next()? {
+            "0" => false,
+            "1" => true,
+            _ => return Err(ParseStateError),
+        };
+
+        Ok(Self {
+            position,
+            size,
+            maximized,
+            monitor: next().ok().map(ToString::to_string),
+        })
+    }
+}
+
+/// Invalid saved [`WindowState`] string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseStateError;
+
+impl Display for ParseStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid window state")
+    }
+}
+
+impl Error for ParseStateError {}
+
+/// Storage of saved window states, like a config file or registry
+pub trait StateStore: Send {
+    fn load(&mut self, key: &str) -> Option<WindowState>;
+
+    fn save(&mut self, key: &str, state: &WindowState);
+}
+
+static STORE: Mutex<Option<Box<dyn StateStore>>> = const_mutex(None);
+
+/// Set store used for loading and saving window states
+pub fn set_store(store: impl StateStore + 'static) {
+    *STORE.lock() = Some(Box::new(store));
+}
+
+/// Load saved state from the store
+pub fn load(key: &str) -> Option<WindowState> {
+    STORE.lock().as_mut()?.load(key)
+}
+
+fn save(key: &str, state: &WindowState) {
+    if let Some(store) = STORE.lock().as_mut() {
+        store.save(key, state);
+    }
+}
+
+/// Create new window with state saved under `key` applied, and start recording its state
+///
+/// See [`build_window`]
+pub fn build_window_restored(
+    attributes: WindowAttributes,
+    key: impl Into<String>,
+) -> Result<Arc<Window>, OsError> {
+    let key = key.into();
+
+    let attributes = match load(&key) {
+        Some(state) => state.apply(attributes),
+        None => attributes,
+    };
+
+    let window = Arc::new(build_window(attributes)?);
+    track(&window, key);
+
+    Ok(window)
+}
+
+/// Record state of the window until it is destroyed
+fn track(window: &Arc<Window>, key: String) {
+    let id = window.id();
+    let mut state = WindowState::capture(window);
+    let window = Arc::downgrade(window);
+
+    spawn_ui_task(crate::window().on(move |(window_id, event), flow| {
+        if window_id != id || flow.done() {
+            return;
+        }
+
+        match event {
+            WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+                if let Some(window) = window.upgrade() {
+                    state.record(&window);
+                }
+            }
+
+            WindowEvent::Focused(false) | WindowEvent::CloseRequested => save(&key, &state),
+
+            WindowEvent::Destroyed => {
+                save(&key, &state);
+                flow.set_done();
+            }
+
+            _ => {}
+        }
+    }))
+    .detach();
+}
@@ -0,0 +1,62 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicIsize, Ordering},
+};
+
+use winit_runtime::{executor::yield_now, spawn_ui_task, test::run_headless};
+
+/// Allocator counting bytes currently allocated
+struct Counting;
+
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size() as isize, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size() as isize, Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const TASKS: usize = 1000;
+
+async fn round() {
+    let tasks = (0..TASKS)
+        .map(|_| spawn_ui_task(async { yield_now().await }))
+        .collect::<Vec<_>>();
+
+    for task in tasks {
+        task.await;
+    }
+}
+
+#[test]
+fn completed_tasks_are_freed() {
+    run_headless(async {
+        // Warm up queues and registry capacity
+        round().await;
+        round().await;
+
+        let before = ALLOCATED.load(Ordering::Relaxed);
+        for _ in 0..10 {
+            round().await;
+        }
+        let grown = ALLOCATED.load(Ordering::Relaxed) - before;
+
+        // Every task leaking its allocation would grow hundreds of kilobytes
+        assert!(grown < 16 * 1024, "{grown} bytes leaked after 10 rounds");
+    });
+}